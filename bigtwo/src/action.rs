@@ -0,0 +1,124 @@
+//! Represents what a Player can do on their turn, of which playing a Hand is just one option.
+
+use alloc::string::{String, ToString};
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::card::notation::{Notation, Render};
+use crate::hand::try_from::ParseHandError;
+use crate::hand::Hand;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents what a Player can do on their turn.
+/// Keeps Pass apart from Hand, so that a Pass can never be compared with or played as a Hand.
+/// More Actions (e.g. conceding) belong here, rather than in Hand.
+/// Serializes as its Display string, e.g. "KS KH" or "Pass".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Action {
+    /// Play a Hand from the Player's cards.
+    Play(Hand),
+    /// Play no Cards, and sit out the rest of the Trick.
+    Pass,
+}
+
+impl Action {
+    /// The Hand played by this Action, if any.
+    pub fn hand(&self) -> Option<&Hand> {
+        match self {
+            Action::Play(hand) => Some(hand),
+            Action::Pass => None,
+        }
+    }
+}
+
+impl From<Hand> for Action {
+    fn from(hand: Hand) -> Self {
+        Action::Play(hand)
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::Play(hand) => write!(f, "{}", hand),
+            Action::Pass => write!(f, "Pass"),
+        }
+    }
+}
+
+impl From<Action> for String {
+    fn from(action: Action) -> Self {
+        action.to_string()
+    }
+}
+
+impl TryFrom<String> for Action {
+    type Error = ParseHandError;
+    fn try_from(action_str: String) -> Result<Self, Self::Error> {
+        action_str.parse()
+    }
+}
+
+impl Render for Action {
+    fn render(&self, notation: Notation) -> String {
+        match self {
+            Action::Play(hand) => hand.render(notation),
+            Action::Pass => self.to_string(),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = ParseHandError;
+
+    /// Either "pass" (in any case), or nothing at all, is a Pass, anything else must be a Hand.
+    fn from_str(action_str: &str) -> Result<Action, Self::Err> {
+        let trimmed = action_str.trim();
+        if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("pass") {
+            Ok(Action::Pass)
+        } else {
+            Ok(Action::Play(trimmed.parse()?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_action_to_from_string() {
+        for pass in ["", "  ", "pass", "Pass", "PASS"] {
+            let action: Action = pass.parse().unwrap();
+            assert_eq!(action, Action::Pass);
+            assert!(action.hand().is_none());
+        }
+        assert_eq!(Action::Pass.to_string(), "Pass");
+
+        let action: Action = "3D 3C".parse().unwrap();
+        assert!(matches!(action, Action::Play(Hand::Pair(..))));
+        assert_eq!(action.to_string(), "3D 3C");
+
+        let action = "4C 3D 3C".parse::<Action>();
+        assert!(matches!(action, Err(ParseHandError::InvalidHand(_))));
+    }
+
+    #[test]
+    fn test_action_serde() {
+        let action: Action = "KS KH".parse().unwrap();
+        let json = serde_json::to_string(&action).unwrap();
+        assert_eq!(json, r#""KS KH""#);
+        assert_eq!(serde_json::from_str::<Action>(&json).unwrap(), action);
+
+        let json = serde_json::to_string(&Action::Pass).unwrap();
+        assert_eq!(json, r#""Pass""#);
+        assert_eq!(serde_json::from_str::<Action>(&json).unwrap(), Action::Pass);
+
+        assert!(serde_json::from_str::<Action>(r#""KS QH""#).is_err());
+        assert!(serde_json::from_str::<Hand>(r#""Pass""#).is_err());
+    }
+}
@@ -1,33 +1,291 @@
 #![doc = include_str!("../../README.md")]
 
-use bigtwo::card::cards_to_string;
-use bigtwo::game::Game;
+use bigtwo::action::Action;
+use bigtwo::card::notation::{Notation, Render};
+use bigtwo::game::config::GameConfig;
+use bigtwo::game::observer::{GameEvent, GameObserver};
+use bigtwo::game::record::GameRecord;
+use bigtwo::game::{Game, NUM_PLAYERS};
+use bigtwo::player::controller::{BotKind, Controller};
+use bigtwo::player::status::SeatStatus;
 
 use std::io;
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Where the Game is saved on Ctrl-C, unless it was resumed from somewhere else.
+const DEFAULT_SAVE_FILE: &str = "bigtwo-save.json";
+
+/// Exit code for being interrupted by Ctrl-C, by convention 128 + SIGINT.
+const INTERRUPTED: i32 = 130;
 
 fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(usage) => {
+            eprintln!("{}", usage);
+            process::exit(2);
+        }
+    };
+
     println!("-------------------");
     println!("Welcome to Big Two!");
     println!("Submit hands by typing the cards in e.g. \"3C 3D 3S\"");
+    println!("Type \"undo\" to take back your last turn, \"redo\" to take it again");
+    println!("Type \"bot\" to let a bot play for you, \"resign\" to give up");
+    println!("Press Ctrl-C to save and quit, then continue with --resume <file>");
     println!("-------------------");
 
-    let mut game = Game::default();
+    let (mut game, save_file) = match args.resume {
+        Some(file) => match std::fs::read_to_string(&file)
+            .map_err(|e| e.to_string())
+            .and_then(|json| Game::load_json(&json).map_err(|e| e.to_string()))
+        {
+            Ok(game) => {
+                println!("Resuming {}", file);
+                (game, file)
+            }
+            Err(e) => {
+                eprintln!("Could not resume {}: {}", file, e);
+                process::exit(1);
+            }
+        },
+        // seeded, so that the seed can be saved along with the Game
+        None => match GameConfig::new().seed(rand::random()).build() {
+            Ok(game) => (game, DEFAULT_SAVE_FILE.to_string()),
+            Err(e) => {
+                eprintln!("Could not start a game: {}", e);
+                process::exit(1);
+            }
+        },
+    };
+    let latest_save = auto_save_on_ctrl_c(save_file, &game);
+    let commentary = Commentary {
+        names: game.players.each_ref().map(|player| player.name.clone()),
+    };
+    game.add_observer(commentary);
 
     while game.is_on() {
-        let input = match game.current_player_idx {
-            0 => {
-                println!(
-                    "Your remaining cards: {}",
-                    cards_to_string(&game.players[game.current_player_idx].cards)
-                );
-                get_player_turn()
-            }
-            _ => game.get_npc_turn().to_string(),
+        if let Ok(json) = game.save_json() {
+            *latest_save.lock().unwrap_or_else(|e| e.into_inner()) = json;
+        }
+        let seat = game.current_seat();
+        if game.seat_status(seat) == SeatStatus::TakenOver(Controller::Human) {
+            println!("A bot is playing for you, press Enter to let it, or type \"reclaim\"");
+            if get_player_turn().eq_ignore_ascii_case("reclaim") {
+                if let Err(e) = game.reclaim(seat) {
+                    println!("{}", e);
+                }
+                continue;
+            }
+        }
+        let result = match game.current_player().controller {
+            Controller::Human => {
+                println!("Your remaining cards:");
+                println!("{}", game.view(seat).cards.render(Notation::AsciiArt));
+                let input = get_player_turn();
+                if input.eq_ignore_ascii_case("undo") {
+                    undo_turn(&mut game);
+                    continue;
+                } else if input.eq_ignore_ascii_case("redo") {
+                    redo_turn(&mut game);
+                    continue;
+                } else if input.eq_ignore_ascii_case("bot") {
+                    if let Err(e) = game.take_over(seat, BotKind::Basic) {
+                        println!("{}", e);
+                    }
+                    continue;
+                } else if input.eq_ignore_ascii_case("resign") {
+                    // the bots play the Game out without you
+                    if let Err(e) = game.forfeit(seat) {
+                        println!("{}", e);
+                    }
+                    continue;
+                }
+                game.step(input.as_str())
+            }
+            // there is no network in the local client, so a bot stands in for Remote players
+            Controller::Bot(_) | Controller::Remote => {
+                let action = game.get_npc_turn().unwrap_or_else(|e| {
+                    println!("NPC could not decide, passing: {}", e);
+                    Action::Pass
+                });
+                game.apply(seat, action)
+            }
         };
-        if let Err(e) = game.step(input.as_str()) {
+        if let Err(e) = result {
             println!("{}", e);
         }
     }
+
+    if let Some(file) = args.export {
+        export(&game, &file);
+    }
+}
+
+/// What the command line asked for.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    /// The saved Game to continue.
+    resume: Option<String>,
+    /// Where to write the record of the Game once it is over.
+    export: Option<String>,
+}
+
+/// Reads the command line, returns the Args, or the usage.
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let usage = "usage: local [--resume <file>] [--export <file>]".to_string();
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        let option = match arg.as_str() {
+            "--resume" => &mut parsed.resume,
+            "--export" => &mut parsed.export,
+            _ => return Err(usage),
+        };
+        match args.next() {
+            Some(file) if option.is_none() => *option = Some(file),
+            _ => return Err(usage),
+        }
+    }
+    Ok(parsed)
+}
+
+/// Writes the record of the Game, dated today, see GameRecord.
+fn export(game: &Game, file: &str) {
+    let mut record = match GameRecord::from_game(game) {
+        Ok(record) => record,
+        Err(e) => return eprintln!("Could not export the game: {}", e),
+    };
+    record.date = Some(today());
+    match std::fs::write(file, record.to_string()) {
+        Ok(()) => println!("Exported the game to {}", file),
+        Err(e) => eprintln!("Could not export to {}: {}", file, e),
+    }
+}
+
+/// Today's date in UTC, e.g. "2026-10-18".
+fn today() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    date(secs)
+}
+
+/// The date in UTC this many seconds after 1970-01-01, e.g. "2026-10-18".
+fn date(secs: u64) -> String {
+    // days since 1970-01-01 to a date, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Writes the most recently saved Game to the file when Ctrl-C is pressed, then exits.
+/// Returns where to keep the most recently saved Game, which starts out as this Game.
+fn auto_save_on_ctrl_c(save_file: String, game: &Game) -> Arc<Mutex<String>> {
+    let latest_save = Arc::new(Mutex::new(game.save_json().unwrap_or_default()));
+    let to_write = latest_save.clone();
+    let handler = move || {
+        let json = to_write.lock().unwrap_or_else(|e| e.into_inner());
+        // never overwrite an earlier save with nothing
+        if json.is_empty() {
+            eprintln!("\nNothing to save");
+            process::exit(INTERRUPTED);
+        }
+        match std::fs::write(&save_file, json.as_bytes()) {
+            Ok(()) => println!(
+                "\nSaved to {}, continue with --resume {}",
+                save_file, save_file
+            ),
+            Err(e) => eprintln!("\nCould not save to {}: {}", save_file, e),
+        }
+        process::exit(INTERRUPTED);
+    };
+    if let Err(e) = ctrlc::set_handler(handler) {
+        eprintln!("Could not set up saving on Ctrl-C: {}", e);
+    }
+    latest_save
+}
+
+/// Undoes everything back to before the current Player's last turn, bots' turns included.
+fn undo_turn(game: &mut Game) {
+    let seat = game.current_seat();
+    if !game.can_undo() || !game.log().actions().any(|(taken_by, _)| taken_by == seat) {
+        println!("Nothing to undo");
+        return;
+    }
+    while let Some((undone, _)) = game.undo() {
+        if undone == seat {
+            return;
+        }
+    }
+}
+
+/// Redoes the current Player's undone turn, and the bots' turns after it.
+fn redo_turn(game: &mut Game) {
+    let seat = game.current_seat();
+    if game.redo().is_none() {
+        println!("Nothing to redo");
+        return;
+    }
+    while game.current_seat() != seat && game.can_redo() {
+        game.redo();
+    }
+}
+
+/// Prints what happens in the Game to stdout.
+struct Commentary {
+    names: [String; NUM_PLAYERS],
+}
+
+impl GameObserver for Commentary {
+    fn on_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::Dealt => println!("Dealing Cards..."),
+            GameEvent::TurnStarted(seat) => println!("{}'s turn", self.names[seat.index()]),
+            GameEvent::Played(seat, hand) => println!(
+                "{} played {}",
+                self.names[seat.index()],
+                hand.render(Notation::Unicode)
+            ),
+            GameEvent::Passed(seat) => println!("{} passed", self.names[seat.index()]),
+            GameEvent::TrickWon(seat) => println!("{} won the trick", self.names[seat.index()]),
+            GameEvent::Undone(seat, action) => println!(
+                "{} took back {}",
+                self.names[seat.index()],
+                action.render(Notation::Unicode)
+            ),
+            GameEvent::PlayerOut(seat) => println!("{} is out of cards", self.names[seat.index()]),
+            GameEvent::TimedOut(seat) => println!("{} ran out of time", self.names[seat.index()]),
+            GameEvent::Forfeited(seat) => println!("{} forfeited", self.names[seat.index()]),
+            GameEvent::SeatStatusChanged(seat, status) => {
+                println!("{} is now: {}", self.names[seat.index()], status)
+            }
+            GameEvent::GameOver(standings) => {
+                println!("Game over!");
+                for (place, standing) in standings.iter().enumerate() {
+                    println!(
+                        "{}. {} with {} cards left",
+                        place + 1,
+                        self.names[standing.seat.index()],
+                        standing.cards_left
+                    );
+                }
+            }
+        }
+    }
 }
 
 fn get_player_turn() -> String {
@@ -47,3 +305,44 @@ fn get_player_turn() -> String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn args(line: &str) -> Result<Args, String> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(args(""), Ok(Args::default()));
+        assert_eq!(
+            args("--export game.txt --resume save.json"),
+            Ok(Args {
+                resume: Some("save.json".into()),
+                export: Some("game.txt".into()),
+            })
+        );
+        for bad in [
+            "--resume a.json --resume b.json",
+            "--resume",
+            "--export game.txt --resume",
+            "--help",
+            "save.json",
+        ] {
+            assert!(args(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(86_399), "1970-01-01");
+        assert_eq!(date(951_782_400), "2000-02-29");
+        assert_eq!(date(951_868_800), "2000-03-01");
+        assert_eq!(date(1_798_675_200), "2026-12-31");
+        assert_eq!(today().len(), "2026-10-18".len());
+    }
+}
@@ -1,13 +1,19 @@
 //! Represents a Standard-52 Playing Card.
 
+pub mod notation;
 pub mod rank;
+pub mod set;
 pub mod suit;
 
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
 use rank::Rank;
 use suit::Suit;
 
 use core::fmt;
-use std::str::FromStr;
+use core::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +33,20 @@ pub struct Card {
     pub suit: Suit,
 }
 
+impl Card {
+    /// Numbers every Card from 0 (Three of Clubs) to 51 (Two of Spades), in the same order as Ord.
+    pub fn index(&self) -> u8 {
+        self.rank as u8 * 4 + self.suit as u8
+    }
+
+    /// The Card with this index, see Card::index, or None if the index is 52 or more.
+    pub fn from_index(index: u8) -> Option<Card> {
+        let rank = *Rank::all().get(usize::from(index / 4))?;
+        let suit = Suit::all()[usize::from(index % 4)];
+        Some(Card { rank, suit })
+    }
+}
+
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}{}", self.rank, self.suit)
@@ -68,13 +88,12 @@ impl FromStr for Card {
     fn from_str(cell_str: &str) -> Result<Self, Self::Err> {
         match &cell_str.chars().collect::<Vec<char>>()[..] {
             [] => Err(Self::Err::Empty),
-            chars if chars.len() != 2 => Err(Self::Err::BadLength),
             [rank_char, suit_char] => {
                 let rank = rank_char.to_string().parse::<Rank>()?;
                 let suit = suit_char.to_string().parse::<Suit>()?;
                 Ok(Card { rank, suit })
             }
-            _ => unreachable!(),
+            _ => Err(Self::Err::BadLength),
         }
     }
 }
@@ -109,12 +128,26 @@ mod tests {
         let good_cells = ["2S", "3C", "KD", "AH", "TS", "QC", "JD"];
         for expected_cell in good_cells {
             let cell = expected_cell.parse::<Card>();
-            assert!(matches!(cell, Ok(_)));
+            assert!(cell.is_ok());
             let result_cell = cell.unwrap().to_string();
             assert_eq!(expected_cell, result_cell);
         }
     }
 
+    #[test]
+    fn test_card_index() {
+        assert_eq!(THREE_OF_CLUBS.index(), 0);
+        assert_eq!("2S".parse::<Card>().unwrap().index(), 51);
+        assert!(Card::from_index(52).is_none());
+        for index in 0..52 {
+            let card = Card::from_index(index).unwrap();
+            assert_eq!(card.index(), index);
+            if let Some(next) = Card::from_index(index + 1) {
+                assert!(card < next);
+            }
+        }
+    }
+
     #[test]
     fn test_card_order() {
         assert!("2S".parse::<Card>().unwrap() > "2D".parse::<Card>().unwrap());
@@ -0,0 +1,174 @@
+//! Renders Cards (and anything made of Cards) in one of several selectable notations.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::card::rank::Rank;
+use crate::card::suit::Suit;
+use crate::card::Card;
+
+/// The different ways a Card can be written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// The same as Display, e.g. "3C", this is also what FromStr accepts.
+    Short,
+    /// Rank followed by the Unicode suit symbol, e.g. "3♣".
+    Unicode,
+    /// The single Unicode Playing Card code point, e.g. "🃓".
+    PlayingCard,
+    /// A multi-line ASCII art card face, several Cards are laid out side by side.
+    AsciiArt,
+    /// The long English name, e.g. "Three of Clubs".
+    Long,
+}
+
+/// Implemented by everything that can be rendered in any Notation.
+pub trait Render {
+    fn render(&self, notation: Notation) -> String;
+}
+
+/// Number of text lines in one ASCII art card face.
+const ASCII_ART_HEIGHT: usize = 5;
+
+impl Card {
+    /// The Unicode Playing Card code point for this Card, there is a block of them per Suit.
+    fn playing_card(&self) -> char {
+        let suit_base: u32 = match self.suit {
+            Suit::Spades => 0x1F0A0,
+            Suit::Hearts => 0x1F0B0,
+            Suit::Diamonds => 0x1F0C0,
+            Suit::Clubs => 0x1F0D0,
+        };
+        // the block goes Ace, Two, ... Ten, Jack, Knight, Queen, King. We skip the Knight.
+        let rank_offset: u32 = match self.rank {
+            Rank::Ace => 0x1,
+            Rank::Two => 0x2,
+            Rank::Jack => 0xB,
+            Rank::Queen => 0xD,
+            Rank::King => 0xE,
+            // Three is 0, and is the third card of the block
+            numeric => numeric as u32 + 0x3,
+        };
+        char::from_u32(suit_base + rank_offset).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Each line of the ASCII art card face of this Card, top to bottom.
+    fn ascii_art(&self) -> [String; ASCII_ART_HEIGHT] {
+        [
+            "+-----+".to_string(),
+            format!("|{}    |", self.rank),
+            format!("|  {}  |", self.suit),
+            format!("|    {}|", self.rank),
+            "+-----+".to_string(),
+        ]
+    }
+}
+
+impl Render for Card {
+    fn render(&self, notation: Notation) -> String {
+        match notation {
+            Notation::Short => self.to_string(),
+            Notation::Unicode => format!("{}{}", self.rank, self.suit.symbol()),
+            Notation::PlayingCard => self.playing_card().to_string(),
+            Notation::AsciiArt => self.ascii_art().join("\n"),
+            Notation::Long => format!("{} of {}", self.rank.name(), self.suit.name()),
+        }
+    }
+}
+
+impl Render for [Card] {
+    fn render(&self, notation: Notation) -> String {
+        match notation {
+            Notation::AsciiArt => {
+                if self.is_empty() {
+                    return String::new();
+                }
+                let faces: Vec<[String; ASCII_ART_HEIGHT]> =
+                    self.iter().map(|card| card.ascii_art()).collect();
+                (0..ASCII_ART_HEIGHT)
+                    .map(|line| {
+                        faces
+                            .iter()
+                            .map(|face| face[line].as_str())
+                            .collect::<Vec<&str>>()
+                            .join(" ")
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            }
+            Notation::Long => self
+                .iter()
+                .map(|card| card.render(notation))
+                .collect::<Vec<String>>()
+                .join(", "),
+            _ => self
+                .iter()
+                .map(|card| card.render(notation))
+                .collect::<Vec<String>>()
+                .join(" "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::hand::Hand;
+    use crate::player::Player;
+    use crate::tests::test_util::vec_card_from_str;
+
+    #[test]
+    fn test_render_card() {
+        let card: Card = "3C".parse().unwrap();
+        assert_eq!(card.render(Notation::Short), "3C");
+        assert_eq!(card.render(Notation::Unicode), "3♣");
+        assert_eq!(card.render(Notation::PlayingCard), "🃓");
+        assert_eq!(card.render(Notation::Long), "Three of Clubs");
+
+        let card: Card = "AS".parse().unwrap();
+        assert_eq!(card.render(Notation::PlayingCard), "🂡");
+        let card: Card = "QH".parse().unwrap();
+        assert_eq!(card.render(Notation::PlayingCard), "🂽");
+        let card: Card = "TD".parse().unwrap();
+        assert_eq!(card.render(Notation::PlayingCard), "🃊");
+        assert_eq!(card.render(Notation::Unicode), "T♦");
+        assert_eq!(card.render(Notation::Long), "Ten of Diamonds");
+    }
+
+    #[test]
+    fn test_render_cards() {
+        let cards = vec_card_from_str("3C KH");
+        assert_eq!(cards.render(Notation::Short), "3C KH");
+        assert_eq!(cards.render(Notation::Unicode), "3♣ K♥");
+        assert_eq!(
+            cards.render(Notation::Long),
+            "Three of Clubs, King of Hearts"
+        );
+        assert_eq!(
+            cards.render(Notation::AsciiArt),
+            [
+                "+-----+ +-----+",
+                "|3    | |K    |",
+                "|  C  | |  H  |",
+                "|    3| |    K|",
+                "+-----+ +-----+",
+            ]
+            .join("\n")
+        );
+        assert_eq!(cards[..0].render(Notation::AsciiArt), "");
+    }
+
+    #[test]
+    fn test_render_hand_and_player() {
+        let hand: Hand = "KS KH".parse().unwrap();
+        assert_eq!(hand.render(Notation::Unicode), "K♠ K♥");
+
+        let player = Player {
+            cards: vec_card_from_str("3C 2S"),
+            ..Default::default()
+        };
+        assert_eq!(player.render(Notation::PlayingCard), "🃓 🂢");
+    }
+}
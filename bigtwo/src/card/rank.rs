@@ -1,8 +1,8 @@
 //! Represents the "number" on a Standard-52 card, ordered.
 
 use core::fmt;
+use core::str::FromStr;
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
 
 /// Represents the "number" on a Standard-52 card, ordered.
 /// The convention in Big Two is:
@@ -24,7 +24,7 @@ pub enum Rank {
     Two,
 }
 
-/// A convenience for iterating through the enums without std::ops::Index trait.
+/// A convenience for iterating through the enums without core::ops::Index trait.
 /// TODO: replace with Index trait and/or Iterator trait
 const RANKS: [Rank; 13] = [
     Rank::Three,
@@ -48,6 +48,44 @@ impl Rank {
     pub fn all() -> [Rank; 13] {
         RANKS
     }
+
+    /// The English name of this Rank, e.g. "Seven".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+            Rank::Ace => "Ace",
+            Rank::Two => "Two",
+        }
+    }
+
+    /// The English plural of this Rank, e.g. "Sixes".
+    pub fn plural(&self) -> &'static str {
+        match self {
+            Rank::Three => "Threes",
+            Rank::Four => "Fours",
+            Rank::Five => "Fives",
+            Rank::Six => "Sixes",
+            Rank::Seven => "Sevens",
+            Rank::Eight => "Eights",
+            Rank::Nine => "Nines",
+            Rank::Ten => "Tens",
+            Rank::Jack => "Jacks",
+            Rank::Queen => "Queens",
+            Rank::King => "Kings",
+            Rank::Ace => "Aces",
+            Rank::Two => "Twos",
+        }
+    }
 }
 
 impl fmt::Display for Rank {
@@ -131,7 +169,7 @@ mod tests {
         let good_ranks = ["3", "7", "J", "A", "2"];
         for expected_rank in good_ranks {
             let rank = expected_rank.parse::<Rank>();
-            assert!(matches!(rank, Ok(_)));
+            assert!(rank.is_ok());
             let result_rank = rank.unwrap().to_string();
             assert_eq!(expected_rank, result_rank);
         }
@@ -0,0 +1,253 @@
+//! A compact, Copy-able set of Cards, one bit per Card.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::card::notation::{Notation, Render};
+use crate::card::rank::Rank;
+use crate::card::suit::Suit;
+use crate::card::Card;
+use crate::hand::Hand;
+
+/// A set of Cards, stored as one bit per Card (see Card::index), so copying, comparing and
+/// hashing it is as cheap as for a u64. Iterates in ascending order.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "Vec<Card>", into = "Vec<Card>")]
+pub struct CardSet(u64);
+
+/// The bits of all 52 Cards.
+const FULL: u64 = (1 << 52) - 1;
+
+/// The bits of the four Cards of the lowest Rank, shift by 4 per Rank.
+const ONE_RANK: u64 = 0xF;
+
+/// The bits of the thirteen Cards of the lowest Suit, shift by 1 per Suit.
+const ONE_SUIT: u64 = 0x1_1111_1111_1111;
+
+impl CardSet {
+    /// No Cards.
+    pub const fn new() -> Self {
+        CardSet(0)
+    }
+
+    /// All 52 Cards.
+    pub const fn full() -> Self {
+        CardSet(FULL)
+    }
+
+    /// One bit per Card, bit n is the Card with index n.
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if the Card was not already in the set.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let had = self.contains(&card);
+        self.0 |= 1 << card.index();
+        !had
+    }
+
+    /// Returns true if the Card was in the set.
+    pub fn remove(&mut self, card: &Card) -> bool {
+        let had = self.contains(card);
+        self.0 &= !(1 << card.index());
+        had
+    }
+
+    pub fn contains(&self, card: &Card) -> bool {
+        self.0 & (1 << card.index()) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The lowest Card in the set, if any.
+    pub fn lowest(&self) -> Option<Card> {
+        self.iter().next()
+    }
+
+    /// All the Cards of this Hand.
+    pub fn from_hand(hand: &Hand) -> Self {
+        hand.cards().copied().collect()
+    }
+
+    /// True if every Card of this Hand is in the set.
+    pub fn contains_hand(&self, hand: &Hand) -> bool {
+        self.is_superset(&CardSet::from_hand(hand))
+    }
+
+    pub fn is_superset(&self, other: &CardSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn union(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// The Cards in this set that are not in the other.
+    pub fn difference(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// The Cards in this set of the given Rank.
+    pub fn of_rank(&self, rank: Rank) -> CardSet {
+        CardSet(self.0 & (ONE_RANK << (rank as u32 * 4)))
+    }
+
+    /// The Cards in this set of the given Suit.
+    pub fn of_suit(&self, suit: Suit) -> CardSet {
+        CardSet(self.0 & (ONE_SUIT << suit as u32))
+    }
+
+    /// Iterates through the Cards in ascending order.
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    pub fn to_vec(&self) -> Vec<Card> {
+        self.iter().collect()
+    }
+}
+
+/// Iterates through a CardSet in ascending order.
+#[derive(Debug, Clone)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros();
+        // clear the lowest bit
+        self.0 &= self.0 - 1;
+        Card::from_index(index as u8)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = CardSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = &'a Card>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl From<Vec<Card>> for CardSet {
+    fn from(cards: Vec<Card>) -> Self {
+        cards.into_iter().collect()
+    }
+}
+
+impl From<CardSet> for Vec<Card> {
+    fn from(set: CardSet) -> Self {
+        set.to_vec()
+    }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(Notation::Short))
+    }
+}
+
+impl Render for CardSet {
+    fn render(&self, notation: Notation) -> String {
+        self.to_vec().render(notation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tests::test_util::vec_card_from_str;
+
+    #[test]
+    fn test_card_set() {
+        let mut set: CardSet = vec_card_from_str("KH 3C 2S").into();
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.to_string(), "3C KH 2S");
+        assert_eq!(set.lowest(), Some("3C".parse().unwrap()));
+
+        assert!(!set.insert("KH".parse().unwrap()));
+        assert!(set.insert("KD".parse().unwrap()));
+        assert!(set.remove(&"3C".parse().unwrap()));
+        assert!(!set.remove(&"3C".parse().unwrap()));
+        assert_eq!(set.to_string(), "KD KH 2S");
+
+        let hand: Hand = "KH KD".parse().unwrap();
+        assert!(set.contains_hand(&hand));
+        let rest = set.difference(&CardSet::from_hand(&hand));
+        assert_eq!(rest.to_string(), "2S");
+        assert!(!rest.contains_hand(&hand));
+
+        assert_eq!(CardSet::full().len(), 52);
+        for rank in Rank::all() {
+            assert!(CardSet::full().of_rank(rank).iter().all(|c| c.rank == rank));
+            assert_eq!(CardSet::full().of_rank(rank).len(), 4);
+        }
+        for suit in Suit::all() {
+            assert!(CardSet::full().of_suit(suit).iter().all(|c| c.suit == suit));
+            assert_eq!(CardSet::full().of_suit(suit).len(), 13);
+        }
+        assert!(CardSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_card_set_serde() {
+        let set: CardSet = vec_card_from_str("3C 2S").into();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(serde_json::from_str::<CardSet>(&json).unwrap(), set);
+    }
+}
@@ -1,6 +1,6 @@
 //! Represents the suit on a Standard-52 card, ordered.
 use core::fmt;
-use std::str::FromStr;
+use core::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +24,26 @@ impl Suit {
     pub fn all() -> [Suit; 4] {
         SUITS
     }
+
+    /// The English name of this Suit, e.g. "Clubs".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Suit::Clubs => "Clubs",
+            Suit::Diamonds => "Diamonds",
+            Suit::Hearts => "Hearts",
+            Suit::Spades => "Spades",
+        }
+    }
+
+    /// The Unicode symbol of this Suit, e.g. '♣'.
+    pub fn symbol(&self) -> char {
+        match self {
+            Suit::Clubs => '♣',
+            Suit::Diamonds => '♦',
+            Suit::Hearts => '♥',
+            Suit::Spades => '♠',
+        }
+    }
 }
 
 impl fmt::Display for Suit {
@@ -89,7 +109,7 @@ mod tests {
         let good_suits = ["C", "D", "H", "S"];
         for expected_suit in good_suits {
             let suit = expected_suit.parse::<Suit>();
-            assert!(matches!(suit, Ok(_)));
+            assert!(suit.is_ok());
             let result_suit = suit.unwrap().to_string();
             assert_eq!(expected_suit, result_suit);
         }
@@ -0,0 +1,155 @@
+//! Turns a Deck into the Cards each Player is dealt, the way a dealer at a real table would.
+
+pub mod pattern;
+pub mod prearranged;
+pub mod shuffle;
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::game::NUM_PLAYERS;
+use crate::player::seat::Seat;
+
+/// Number of Cards in a full Deck.
+pub const NUM_CARDS_IN_DECK: usize = 52;
+
+/// Number of Cards each Player is dealt.
+pub const NUM_CARDS_PER_PLAYER: usize = NUM_CARDS_IN_DECK / NUM_PLAYERS;
+
+/// The Cards dealt to each Seat, at the index of that Seat.
+pub type Deal = [Vec<Card>; NUM_PLAYERS];
+
+/// Represents the ways a set of Cards can fail to be a fair deal.
+#[derive(Debug)]
+pub enum DealError {
+    /// The Cards are not exactly one of each of the 52 Cards.
+    NotAFullDeck,
+    /// Some Seat was not dealt exactly its share of the Cards.
+    UnevenDeal,
+    /// Nobody holds the Three of Clubs, so nobody can lead without being told to.
+    NoThreeOfClubs,
+    /// This Seat was dealt no Cards at all, so the Game would be over before it started.
+    EmptyHand(Seat),
+}
+
+impl Display for DealError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::NotAFullDeck => write!(f, "the cards are not one of each card in the deck"),
+            Self::UnevenDeal => {
+                write!(f, "every player must be dealt {NUM_CARDS_PER_PLAYER} cards")
+            }
+            Self::NoThreeOfClubs => write!(f, "nobody holds the three of clubs to lead"),
+            Self::EmptyHand(seat) => write!(f, "seat {} was dealt no cards", seat),
+        }
+    }
+}
+
+/// Something that arranges a Deck and deals it out, e.g. a shuffle followed by dealing one Card at
+/// a time around the table.
+/// The top of the Deck is the end of its cards, dealing starts from there and from the first Seat.
+pub trait Dealer {
+    /// Put the Deck in the order it will be dealt in, e.g. by shuffling it.
+    fn arrange(&mut self, deck: &mut Deck);
+
+    /// How many Cards each Player is given at a time, going around the table.
+    fn packet_size(&self) -> usize {
+        1
+    }
+
+    /// Arrange the Deck, then deal all of it out.
+    fn deal(&mut self, mut deck: Deck) -> Deal {
+        self.arrange(&mut deck);
+        deal_in_packets(deck, self.packet_size())
+    }
+}
+
+/// Deal from the top of the Deck, packet_size Cards at a time, to each Seat in turn, until every
+/// Seat has its share. A packet is cut short when the Seat needs fewer Cards than that.
+pub fn deal_in_packets(mut deck: Deck, packet_size: usize) -> Deal {
+    let mut deal: Deal = Default::default();
+    let share = deck.cards.len() / NUM_PLAYERS;
+    let packet_size = packet_size.max(1);
+    while !deck.cards.is_empty() {
+        let before = deck.cards.len();
+        for hand in deal.iter_mut() {
+            let packet = packet_size.min(share - hand.len());
+            for _ in 0..packet {
+                if let Some(card) = deck.cards.pop() {
+                    hand.push(card);
+                }
+            }
+        }
+        // the Deck does not split evenly, the leftover Cards stay in the Deck
+        if deck.cards.len() == before {
+            break;
+        }
+    }
+    deal
+}
+
+/// True when these Cards are exactly one of each of the 52 Cards.
+pub fn is_full_deck<'a, I: IntoIterator<Item = &'a Card>>(cards: I) -> bool {
+    let mut count = 0;
+    let unique: BTreeSet<&Card> = cards.into_iter().inspect(|_| count += 1).collect();
+    count == NUM_CARDS_IN_DECK && unique.len() == NUM_CARDS_IN_DECK
+}
+
+/// Returns an Error unless this Deal gives every Seat its share of one full Deck.
+pub fn validate_deal(deal: &Deal) -> Result<(), DealError> {
+    if deal.iter().any(|hand| hand.len() != NUM_CARDS_PER_PLAYER) {
+        Err(DealError::UnevenDeal)
+    } else if !is_full_deck(deal.iter().flatten()) {
+        Err(DealError::NotAFullDeck)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_deal_in_packets() {
+        for packet_size in [0, 1, 3, 4, 13, 52] {
+            let deck = Deck::new();
+            let top = *deck.cards.last().unwrap();
+            let deal = deal_in_packets(deck, packet_size);
+            assert!(validate_deal(&deal).is_ok());
+            // the first Seat always gets the top Card
+            assert_eq!(deal[0][0], top);
+        }
+
+        // packets of 3 go 3 to each Seat, 4 times, then 1 each
+        let deck = Deck::new();
+        let order: Vec<Card> = deck.cards.iter().rev().copied().collect();
+        let deal = deal_in_packets(deck, 3);
+        assert_eq!(deal[0][..3], order[..3]);
+        assert_eq!(deal[1][..3], order[3..6]);
+        assert_eq!(deal[0][3..6], order[12..15]);
+        assert_eq!(deal[3][12], order[51]);
+    }
+
+    #[test]
+    fn test_validate_deal() {
+        let deal = deal_in_packets(Deck::new(), 1);
+        assert!(validate_deal(&deal).is_ok());
+
+        let mut uneven = deal.clone();
+        let card = uneven[0].pop().unwrap();
+        uneven[1].push(card);
+        assert!(matches!(validate_deal(&uneven), Err(DealError::UnevenDeal)));
+
+        let mut duplicated = deal.clone();
+        duplicated[0][0] = duplicated[1][0];
+        assert!(matches!(
+            validate_deal(&duplicated),
+            Err(DealError::NotAFullDeck)
+        ));
+    }
+}
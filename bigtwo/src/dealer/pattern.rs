@@ -0,0 +1,91 @@
+//! Dealers that change how another Dealer's arrangement is dealt out: cutting the Deck, and
+//! dealing several Cards at a time.
+
+use crate::dealer::Dealer;
+use crate::deck::Deck;
+
+/// Cuts the Deck after another Dealer has arranged it, moving this many Cards from the top of the
+/// Deck to the bottom.
+#[derive(Debug)]
+pub struct Cut<D: Dealer> {
+    inner: D,
+    depth: usize,
+}
+
+impl<D: Dealer> Cut<D> {
+    pub fn new(inner: D, depth: usize) -> Self {
+        Cut { inner, depth }
+    }
+}
+
+impl<D: Dealer> Dealer for Cut<D> {
+    fn arrange(&mut self, deck: &mut Deck) {
+        self.inner.arrange(deck);
+        // the top of the Deck is the end of its cards
+        let depth = self.depth % deck.cards.len().max(1);
+        deck.cards.rotate_right(depth);
+    }
+
+    fn packet_size(&self) -> usize {
+        self.inner.packet_size()
+    }
+}
+
+/// Deals another Dealer's arrangement several Cards at a time, commonly 3 or 4, instead of one.
+#[derive(Debug)]
+pub struct Packets<D: Dealer> {
+    inner: D,
+    size: usize,
+}
+
+impl<D: Dealer> Packets<D> {
+    pub fn new(inner: D, size: usize) -> Self {
+        Packets { inner, size }
+    }
+}
+
+impl<D: Dealer> Dealer for Packets<D> {
+    fn arrange(&mut self, deck: &mut Deck) {
+        self.inner.arrange(deck);
+    }
+
+    fn packet_size(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dealer::prearranged::Prearranged;
+    use crate::dealer::shuffle::UniformShuffle;
+    use crate::dealer::validate_deal;
+
+    #[test]
+    fn test_cut() {
+        let mut deck = Deck::new();
+        let top = *deck.cards.last().unwrap();
+        let bottom = deck.cards[0];
+        Cut::new(Prearranged::in_order(), 1).arrange(&mut deck);
+        // the top card went to the bottom
+        assert_eq!(deck.cards[0], top);
+        assert_eq!(deck.cards[1], bottom);
+
+        let deal = Cut::new(UniformShuffle::seeded(3), 26).deal(Deck::new());
+        assert!(validate_deal(&deal).is_ok());
+    }
+
+    #[test]
+    fn test_packets() {
+        let dealer = Packets::new(UniformShuffle::seeded(3), 4);
+        assert_eq!(dealer.packet_size(), 4);
+        let deal = Packets::new(UniformShuffle::seeded(3), 4).deal(Deck::new());
+        assert!(validate_deal(&deal).is_ok());
+
+        // the packets are dealt from the same arrangement, so the first packet is the same
+        let single = UniformShuffle::seeded(3).deal(Deck::new());
+        assert_eq!(deal[0][0], single[0][0]);
+        assert_eq!(deal[0][1], single[1][0]);
+    }
+}
@@ -0,0 +1,65 @@
+//! A Dealer that deals the Cards in an order decided beforehand, e.g. to recreate a game played at
+//! a real table, or to set up a test.
+
+use alloc::vec::Vec;
+
+use crate::card::Card;
+use crate::dealer::{is_full_deck, DealError, Dealer};
+use crate::deck::Deck;
+
+/// Deals the Cards in exactly the given order, the first Card goes to the first Seat.
+#[derive(Debug, Clone)]
+pub struct Prearranged {
+    order: Vec<Card>,
+}
+
+impl Prearranged {
+    /// Returns an Error unless the order is exactly one of each of the 52 Cards.
+    pub fn new(order: Vec<Card>) -> Result<Self, DealError> {
+        if is_full_deck(&order) {
+            Ok(Prearranged { order })
+        } else {
+            Err(DealError::NotAFullDeck)
+        }
+    }
+
+    /// Deals a new Deck without changing its order at all.
+    pub fn in_order() -> Self {
+        let mut order = Deck::new().cards;
+        order.reverse();
+        Prearranged { order }
+    }
+}
+
+impl Dealer for Prearranged {
+    fn arrange(&mut self, deck: &mut Deck) {
+        // the top of the Deck is the end of its cards, and is dealt first
+        deck.cards.clone_from(&self.order);
+        deck.cards.reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dealer::Dealer;
+
+    #[test]
+    fn test_prearranged() {
+        let mut order = Deck::new().cards;
+        order.sort();
+        let mut dealer = Prearranged::new(order.clone()).unwrap();
+        let deal = dealer.deal(Deck::new());
+        // one at a time around the table, from the first Card of the order
+        assert_eq!(deal[0][0], order[0]);
+        assert_eq!(deal[1][0], order[1]);
+        assert_eq!(deal[0][1], order[4]);
+
+        order.pop();
+        assert!(matches!(
+            Prearranged::new(order),
+            Err(DealError::NotAFullDeck)
+        ));
+    }
+}
@@ -0,0 +1,141 @@
+//! Dealers that shuffle the Deck before dealing it one Card at a time.
+
+use alloc::vec::Vec;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::dealer::Dealer;
+use crate::deck::Deck;
+
+/// Shuffles the Deck so that every order is equally likely.
+/// Uses its own Fisher-Yates shuffle drawing only whole u64s from the rng, rather than rand's
+/// shuffle, whose output may change between versions of rand. With a seeded ChaCha8 rng the deal
+/// is the same on every platform and every version.
+#[derive(Debug)]
+pub struct UniformShuffle<R: Rng> {
+    rng: R,
+}
+
+impl<R: Rng> UniformShuffle<R> {
+    pub fn new(rng: R) -> Self {
+        UniformShuffle { rng }
+    }
+}
+
+impl UniformShuffle<ChaCha8Rng> {
+    /// Shuffles with ChaCha8, so that the same seed gives the same shuffle on every platform.
+    pub fn seeded(seed: u64) -> Self {
+        UniformShuffle::new(ChaCha8Rng::seed_from_u64(seed))
+    }
+}
+
+impl<R: Rng> Dealer for UniformShuffle<R> {
+    fn arrange(&mut self, deck: &mut Deck) {
+        for i in (1..deck.cards.len()).rev() {
+            let j = below(&mut self.rng, i as u64 + 1) as usize;
+            deck.cards.swap(i, j);
+        }
+    }
+}
+
+/// Simulates riffle shuffling a real Deck by hand, using the Gilbert-Shannon-Reeds model: the Deck
+/// is cut roughly in half, then Cards drop from either half with odds proportional to its size.
+/// A handful of riffles is far from uniform, which is the point of studying it.
+#[derive(Debug)]
+pub struct RiffleShuffle<R: Rng> {
+    rng: R,
+    riffles: usize,
+}
+
+impl<R: Rng> RiffleShuffle<R> {
+    /// Riffle the Deck this many times.
+    pub fn new(rng: R, riffles: usize) -> Self {
+        RiffleShuffle { rng, riffles }
+    }
+}
+
+impl RiffleShuffle<ChaCha8Rng> {
+    /// Riffles with ChaCha8, so that the same seed gives the same riffles on every platform.
+    pub fn seeded(seed: u64, riffles: usize) -> Self {
+        RiffleShuffle::new(ChaCha8Rng::seed_from_u64(seed), riffles)
+    }
+}
+
+impl<R: Rng> Dealer for RiffleShuffle<R> {
+    fn arrange(&mut self, deck: &mut Deck) {
+        for _ in 0..self.riffles {
+            riffle(&mut deck.cards, &mut self.rng);
+        }
+    }
+}
+
+/// A uniformly random number from 0 to bound - 1, by rejection sampling of whole u64s.
+fn below<R: Rng>(rng: &mut R, bound: u64) -> u64 {
+    // the largest multiple of bound that fits, anything at or above it would bias the result
+    let zone = u64::MAX - u64::MAX % bound;
+    loop {
+        let x = rng.next_u64();
+        if x < zone {
+            return x % bound;
+        }
+    }
+}
+
+/// One Gilbert-Shannon-Reeds riffle.
+fn riffle<T, R: Rng>(cards: &mut Vec<T>, rng: &mut R) {
+    // the cut is binomially distributed around the middle of the Deck
+    let cut = (0..cards.len()).filter(|_| rng.gen_bool(0.5)).count();
+    let mut right = cards.split_off(cut).into_iter();
+    let mut left = core::mem::take(cards).into_iter();
+    while left.len() + right.len() > 0 {
+        let next = if rng.gen_range(0..left.len() + right.len()) < left.len() {
+            left.next()
+        } else {
+            right.next()
+        };
+        cards.extend(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dealer::validate_deal;
+
+    #[test]
+    fn test_uniform_shuffle() {
+        let deal = UniformShuffle::seeded(7).deal(Deck::new());
+        assert!(validate_deal(&deal).is_ok());
+
+        // same seed, same deal
+        assert_eq!(deal, UniformShuffle::seeded(7).deal(Deck::new()));
+        assert_ne!(deal, UniformShuffle::seeded(8).deal(Deck::new()));
+    }
+
+    #[test]
+    fn test_riffle_shuffle() {
+        let deal = RiffleShuffle::seeded(7, 3).deal(Deck::new());
+        assert!(validate_deal(&deal).is_ok());
+        assert_eq!(deal, RiffleShuffle::seeded(7, 3).deal(Deck::new()));
+
+        // a riffle keeps each half in order, the cards from either half are just interleaved
+        let mut cards: Vec<usize> = (0..52).collect();
+        riffle(&mut cards, &mut ChaCha8Rng::seed_from_u64(1));
+        let mut sorted = cards.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..52).collect::<Vec<usize>>());
+        let mut positions = [0; 52];
+        for (position, card) in cards.iter().enumerate() {
+            positions[*card] = position;
+        }
+        let rising_sequences = 1 + positions.windows(2).filter(|w| w[1] < w[0]).count();
+        assert!(rising_sequences <= 2);
+
+        // no riffles, no change
+        let mut deck = Deck::new();
+        RiffleShuffle::seeded(7, 0).arrange(&mut deck);
+        assert_eq!(deck.cards, Deck::new().cards);
+    }
+}
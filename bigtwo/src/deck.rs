@@ -1,5 +1,7 @@
 //! Represents a full 52 Card Deck of Standard Playing Cards
 
+use alloc::vec::Vec;
+
 use crate::card::rank::Rank;
 use crate::card::suit::Suit;
 use crate::card::Card;
@@ -2,51 +2,147 @@
 
 // use serde::Serialize;
 
+pub mod audit;
+
 mod check_player_can_play_hand;
-use check_player_can_play_hand::check_player_can_play_hand;
-use check_player_can_play_hand::PlayHandError;
+pub use check_player_can_play_hand::PlayHandError;
+
+mod legal_actions;
+pub use legal_actions::legal_actions;
+
+pub mod clock;
+
+pub mod config;
+use clock::Timer;
+
+pub mod log;
+use log::{GameLog, LogEntry};
 
 mod next_player_id;
-use next_player_id::next_player_id;
+pub use next_player_id::NextPlayerError;
+
+pub mod observer;
+use observer::{GameEvent, GameObserver};
+
+pub mod outcome;
+use outcome::StepOutcome;
+
+pub mod position;
+
+pub mod record;
+
+pub mod rules;
+use rules::Rules;
 
-use crate::card::THREE_OF_CLUBS;
+pub mod save;
+
+pub mod seat_status;
+
+pub mod state;
+use state::GameState;
+
+pub mod tricks;
+use tricks::TrickHistory;
+
+mod undo;
+
+pub mod view;
+
+pub mod zobrist;
+
+use crate::action::Action;
+use crate::card::set::CardSet;
+use crate::dealer::shuffle::UniformShuffle;
+use crate::dealer::{is_full_deck, validate_deal, Deal, DealError, Dealer};
 use crate::deck::Deck;
 use crate::hand::Hand;
-use crate::player::get_ai_input::*;
+use crate::player::bot::Bot;
+use crate::player::controller::{BotKind, Controller};
+use crate::player::get_ai_input::AiInputError;
+use crate::player::seat::{PlayerId, Seat};
+use crate::player::status::SeatStatus;
 use crate::player::Player;
 
-use std::collections::BTreeSet;
-use std::fmt::Display;
-use std::str::FromStr;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::{Debug, Display};
+use core::str::FromStr;
 
 use crate::hand::try_from::ParseHandError;
 
+use rand::Rng;
+
 /// There are many variations of this game with non-4 numbers of players, but for now we focus on
 /// the base game.
 pub const NUM_PLAYERS: usize = 4;
 
-/// Represents the current state of a Game
-#[derive(Debug)]
+/// A Game in progress: its GameState, plus the history and the Players around it.
 pub struct Game {
     /// history of all hands played by all players.
     /// the final played hand is the winner.
+    /// see Game::tricks for who played them, and when.
     pub played_hands: Vec<Hand>,
 
-    /// players, and their cards
+    /// players, and their cards, each at the index of their Seat.
+    /// their cards are kept in step with the GameState.
     pub players: [Player; NUM_PLAYERS],
 
-    /// Used to index into a [Player; NUM_PLAYERS]
-    pub current_player_idx: usize,
+    /// What the rules need to know to continue the Game.
+    state: GameState,
 
-    /// Keeps track of all players who have passed so far this Trick
-    pub passed_player_idxs: BTreeSet<usize>,
+    /// The deal and every Action taken since, enough to replay the Game.
+    log: GameLog,
 
-    pub is_start_trick: bool,
+    /// The GameState before each Action in the log, for undo.
+    history: Vec<GameState>,
+
+    /// The Actions undone, most recently undone last, for redo.
+    undone: Vec<(Seat, Action)>,
+
+    /// Every Action in the log, grouped into Tricks.
+    tricks: TrickHistory,
+
+    /// The seed the Cards were shuffled with, if the Game was started with one.
+    seed: Option<u64>,
+
+    /// Told about everything that happens from now on.
+    observers: Vec<Box<dyn GameObserver>>,
+
+    /// The time each Seat has left, None if the Game is not on the clock.
+    timer: Option<Timer>,
 }
 
+impl Debug for Game {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Game")
+            .field("played_hands", &self.played_hands)
+            .field("players", &self.players)
+            .field("state", &self.state)
+            .field("log", &self.log)
+            .field("history", &self.history)
+            .field("undone", &self.undone)
+            .field("tricks", &self.tricks)
+            .field("seed", &self.seed)
+            .field("observers", &self.observers.len())
+            .field("timer", &self.timer)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
 pub enum GameStepError {
     ParseHandError(ParseHandError),
     PlayHandError(PlayHandError),
+    NextPlayerError(NextPlayerError),
+    /// Somebody is already out of Cards, nothing more can be played.
+    GameOver,
+    /// This Seat tried to act, but it is not its turn.
+    NotYourTurn(Seat),
+    /// This Seat has forfeited, it takes no further part in the Game.
+    Forfeited(Seat),
+    /// This Seat ran out of time, see Game::enforce_time_control.
+    OutOfTime(Seat),
 }
 
 impl From<ParseHandError> for GameStepError {
@@ -61,144 +157,527 @@ impl From<PlayHandError> for GameStepError {
     }
 }
 
+impl From<NextPlayerError> for GameStepError {
+    fn from(e: NextPlayerError) -> Self {
+        Self::NextPlayerError(e)
+    }
+}
+
 impl Display for GameStepError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             Self::ParseHandError(e) => write!(f, "ParseHandError! {}", e),
             Self::PlayHandError(e) => write!(f, "PlayHandError! {}", e),
+            Self::NextPlayerError(e) => write!(f, "NextPlayerError! {}", e),
+            Self::GameOver => write!(f, "the game is over"),
+            Self::NotYourTurn(seat) => write!(f, "it is not seat {}'s turn", seat),
+            Self::Forfeited(seat) => write!(f, "seat {} has forfeited", seat),
+            Self::OutOfTime(seat) => write!(f, "seat {} is out of time", seat),
         }
     }
 }
 
+/// Shuffles with the thread-local rng, so every Game is different.
+#[cfg(feature = "std")]
 impl Default for Game {
     fn default() -> Self {
-        let deck: Deck = Deck::new();
-        let mut players: [Player; NUM_PLAYERS] = <[Player; NUM_PLAYERS]>::default();
-        shuffle_and_deal_cards(&mut players, deck);
-        let starting_player = find_player_with_three_of_clubs(&players);
-        let game = Game {
+        Game::with_rng(&mut rand::thread_rng())
+    }
+}
+
+impl Game {
+    /// Shuffles and deals using the given rng, which is all a Game needs from its environment.
+    pub fn with_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        // a uniform shuffle of a full Deck is always a valid Deal
+        let deal = UniformShuffle::new(rng).deal(Deck::new());
+        Game::from_valid_deal(deal, None, Rules::default())
+    }
+
+    /// Shuffles with ChaCha8 seeded with this seed, so the same seed deals the same Cards on
+    /// every platform and with every version of this crate.
+    pub fn with_seed(seed: u64) -> Self {
+        Game::seeded(seed, Rules::default())
+    }
+
+    /// Game::with_seed, played by these Rules.
+    pub(crate) fn seeded(seed: u64, rules: Rules) -> Self {
+        let deal = UniformShuffle::seeded(seed).deal(Deck::new());
+        let mut game = Game::from_valid_deal(deal, None, rules);
+        game.seed = Some(seed);
+        game
+    }
+
+    /// The seed the Cards were shuffled with, if the Game was started with Game::with_seed.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Deals a full Deck using any Dealer, returns an Error if the Dealer did not deal fairly.
+    pub fn with_dealer<D: Dealer + ?Sized>(dealer: &mut D) -> Result<Self, DealError> {
+        Game::with_deal(dealer.deal(Deck::new()))
+    }
+
+    /// Starts a Game with these Cards dealt to each Seat, returns an Error if they are not a fair
+    /// deal of a full Deck.
+    pub fn with_deal(deal: Deal) -> Result<Self, DealError> {
+        validate_deal(&deal)?;
+        Ok(Game::from_valid_deal(deal, None, Rules::default()))
+    }
+
+    /// Starts a Game from an explicit position: these Cards in each Seat's hand, and this Seat to
+    /// lead. The leader must open with the Three of Clubs if they hold it, otherwise they may open
+    /// with anything. Hands may be of any size but empty, and together they must be exactly one
+    /// full Deck.
+    pub fn from_hands(hands: Deal, leader: Seat) -> Result<Self, DealError> {
+        Game::from_hands_with_rules(hands, leader, Rules::default())
+    }
+
+    /// Game::from_hands, played by these Rules.
+    pub(crate) fn from_hands_with_rules(
+        hands: Deal,
+        leader: Seat,
+        rules: Rules,
+    ) -> Result<Self, DealError> {
+        if !is_full_deck(hands.iter().flatten()) {
+            return Err(DealError::NotAFullDeck);
+        }
+        if let Some(seat) = Seat::all()
+            .into_iter()
+            .find(|seat| hands[seat.index()].is_empty())
+        {
+            return Err(DealError::EmptyHand(seat));
+        }
+        Ok(Game::from_valid_deal(hands, Some(leader), rules))
+    }
+
+    /// Seats the Players and hands them their Cards, the Deal must already be validated.
+    /// Without a leader, whoever holds the Three of Clubs leads.
+    pub(crate) fn from_valid_deal(mut deal: Deal, leader: Option<Seat>, rules: Rules) -> Self {
+        // the first Seat is played by the person at the keyboard, the rest by bots
+        let mut players: [Player; NUM_PLAYERS] = Seat::all().map(|seat| {
+            let controller = if seat.index() == 0 {
+                Controller::Human
+            } else {
+                Controller::Bot(BotKind::Basic)
+            };
+            let name = format!("Player {}", seat.index() + 1);
+            Player::new(PlayerId(seat.index() as u64), name, seat, controller)
+        });
+        for (player, cards) in players.iter_mut().zip(deal.iter_mut()) {
+            cards.sort();
+            player.cards = cards.clone();
+        }
+        let hands = players
+            .each_ref()
+            .map(|player| player.cards.iter().collect::<CardSet>());
+        // a full Deck always has the Three of Clubs, so whoever was dealt it leads
+        let leader = leader
+            .or_else(|| GameState::three_of_clubs_holder(&hands))
+            .unwrap_or_default();
+        let state = GameState::with_rules(hands, leader, rules);
+        Game {
             played_hands: vec![],
             players,
-            current_player_idx: starting_player,
-            passed_player_idxs: BTreeSet::default(),
-            is_start_trick: true,
-        };
+            state,
+            log: GameLog::new(deal, leader, rules),
+            history: vec![],
+            undone: vec![],
+            tricks: TrickHistory::default(),
+            seed: None,
+            observers: vec![],
+            timer: None,
+        }
+    }
 
-        game
+    /// Registers an observer to be told about every GameEvent from now on. If the Game has not
+    /// started yet it is first told about the deal, and whose turn it is, so it can catch up.
+    pub fn add_observer<O: GameObserver + 'static>(&mut self, observer: O) {
+        self.add_boxed_observer(Box::new(observer));
+    }
+
+    /// Game::add_observer, for an observer that is already boxed.
+    pub(crate) fn add_boxed_observer(&mut self, mut observer: Box<dyn GameObserver>) {
+        if self.played_hands.is_empty() {
+            observer.on_event(&GameEvent::Dealt);
+        }
+        if self.is_on() {
+            observer.on_event(&GameEvent::TurnStarted(self.current_seat()));
+        }
+        self.observers.push(observer);
+    }
+
+    /// Tells every observer about the event.
+    fn notify(&mut self, event: &GameEvent) {
+        for observer in &mut self.observers {
+            observer.on_event(event);
+        }
+    }
+
+    /// Every Action so far, grouped into Tricks.
+    pub fn tricks(&self) -> &TrickHistory {
+        &self.tricks
+    }
+
+    /// The current GameState, which can be copied and explored without touching this Game.
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// The house Rules this Game is played by.
+    pub fn rules(&self) -> &Rules {
+        self.state.rules()
+    }
+
+    /// The Seat whose turn it is.
+    pub fn current_seat(&self) -> Seat {
+        self.state.current_seat()
     }
-}
 
-impl Game {
     /// true when the game is not yet finished
     pub fn is_on(&self) -> bool {
-        for player in &self.players {
-            if player.cards.is_empty() {
-                return false;
-            }
+        !self.state.is_over()
+    }
+
+    /// The Player sitting at this Seat.
+    pub fn player(&self, seat: Seat) -> &Player {
+        &self.players[seat.index()]
+    }
+
+    /// The Player whose turn it is.
+    pub fn current_player(&self) -> &Player {
+        self.player(self.current_seat())
+    }
+
+    /// Every Action the Player at this Seat may take right now, empty if it is not their turn.
+    pub fn legal_actions(&self, seat: Seat) -> Vec<Action> {
+        if seat == self.current_seat() {
+            self.state.legal_actions()
+        } else {
+            Vec::new()
         }
-        return true;
     }
 
-    /// step the game based on input
-    pub fn step(&mut self, input: &str) -> Result<(), GameStepError> {
+    /// step the game based on text input for the current Seat, returns what happened
+    pub fn step(&mut self, input: &str) -> Result<StepOutcome, GameStepError> {
         // accept / validate input
-        let hand = Hand::from_str(input)?;
+        let action = Action::from_str(input)?;
+        self.apply(self.current_seat(), action)
+    }
 
-        // check if the attempted play is legal
-        let player = &mut self.players[self.current_player_idx];
-        check_player_can_play_hand(self.played_hands.last(), player, &hand, self.is_start_trick)?;
+    /// The Player at this Seat takes this Action, returns what happened.
+    /// Returns an Error and leaves the Game untouched if it is not their turn or the Action is
+    /// not allowed.
+    /// Anything undone can no longer be redone.
+    pub fn apply(&mut self, seat: Seat, action: Action) -> Result<StepOutcome, GameStepError> {
+        if seat == self.current_seat() && self.is_out_of_time() {
+            return Err(GameStepError::OutOfTime(seat));
+        }
+        let outcome = self.advance(seat, action)?;
+        self.undone.clear();
+        Ok(outcome)
+    }
 
-        // either take the player's cards, or add that player to the passed_players set
-        if hand == Hand::Pass {
-            // player passed
-            self.passed_player_idxs.insert(self.current_player_idx);
-        } else {
-            // take player's submitted hand from their cards
-            player.remove_hand_from_cards(&hand);
-            self.played_hands.push(hand);
-        };
+    /// Takes the Action and records it, without touching what can be redone.
+    fn advance(&mut self, seat: Seat, action: Action) -> Result<StepOutcome, GameStepError> {
+        if seat != self.current_seat() {
+            return Err(GameStepError::NotYourTurn(seat));
+        }
 
-        // advance to next player, skipping any player that has already passed
-        self.current_player_idx = next_player_id(
-            self.current_player_idx,
-            &self.passed_player_idxs,
-            NUM_PLAYERS,
-        );
+        // the GameState decides if the attempted play is legal
+        let next = self.state.apply(&action)?;
 
-        // if N-1/N players have passed, start new trick
-        if self.passed_player_idxs.len() == NUM_PLAYERS - 1 {
-            self.passed_player_idxs.clear();
-            self.is_start_trick = true;
-        } else {
-            self.is_start_trick = false;
+        // mirror the taken cards onto the player, and record the hand
+        if let Action::Play(hand) = action {
+            self.players[seat.index()]
+                .remove_hand_from_cards(&hand)
+                .map_err(|_| PlayHandError::StolenCards)?;
+            self.played_hands.push(hand);
         }
 
         // game state advanced
-        return Ok(());
+        let outcome = StepOutcome::new(&self.state, action, &next);
+        self.history.push(self.state);
+        self.state = next;
+        self.log.push(LogEntry::Action { seat, action });
+        self.tricks.push(seat, action);
+        if let Some(winner) = outcome.trick_winner {
+            self.tricks.end_trick(winner);
+        }
+        if let Some(standings) = &outcome.standings {
+            self.tricks.end_trick(standings[0].seat);
+        }
+        if let Some(timer) = &mut self.timer {
+            timer.end_turn(seat);
+        }
+
+        for event in GameEvent::from_outcome(&outcome) {
+            self.notify(&event);
+        }
+        if self.is_on() {
+            self.notify(&GameEvent::TurnStarted(self.current_seat()));
+        }
+        Ok(outcome)
     }
 
-    /// npc turn
-    pub fn get_npc_turn(&mut self) -> Hand {
-        println!("Player {}s turn", self.current_player_idx + 1);
-        let player: &Player = &self.players[self.current_player_idx];
-        let npc_play = if let Some(last) = self.played_hands.last() {
-            if self.is_start_trick {
-                start_trick_with_lowest_single(&player.cards)
-            } else {
-                play_smallest_single_or_pass(last, &player.cards)
+    /// The Player at this Seat forfeits, e.g. by resigning, whether or not it is their turn. The
+    /// Game carries on without them, and they finish last. A forfeit cannot be undone, nor
+    /// anything before it.
+    pub fn forfeit(&mut self, seat: Seat) -> Result<(), GameStepError> {
+        let before = self.state;
+        self.state = before.forfeit(seat)?;
+        self.players[seat.index()].status = SeatStatus::Forfeited;
+        self.log.push(LogEntry::Forfeit { seat });
+        self.history.clear();
+        self.undone.clear();
+
+        self.notify(&GameEvent::Forfeited(seat));
+        if self.state.is_start_trick() && !before.is_start_trick() {
+            self.tricks.end_trick(self.current_seat());
+            self.notify(&GameEvent::TrickWon(self.current_seat()));
+        }
+        if !self.is_on() {
+            let standings = self.state.standings();
+            self.tricks.end_trick(standings[0].seat);
+            self.notify(&GameEvent::GameOver(standings));
+        } else if self.current_seat() != before.current_seat() {
+            if let Some(timer) = &mut self.timer {
+                timer.restart_turn();
             }
-        } else {
-            play_three_of_clubs(&player.cards)
-        };
-
-        println!("Player {} played {}", self.current_player_idx + 1, npc_play);
-        npc_play
+            self.notify(&GameEvent::TurnStarted(self.current_seat()));
+        }
+        Ok(())
     }
-}
 
-/// Shuffle and Deal the cards just like a regular human dealer.
-/// All players will receive 13 Cards each.
-fn shuffle_and_deal_cards(players: &mut [Player; NUM_PLAYERS], mut deck: Deck) {
-    println!("Dealing Cards...");
-    use rand::seq::SliceRandom;
-    use rand::thread_rng;
-    let mut rng = thread_rng();
-    deck.cards[..].shuffle(&mut rng);
-
-    let mut player_index: usize = 0;
-    while let Some(card) = deck.cards.pop() {
-        let index = player_index % NUM_PLAYERS;
-        players[index].cards.push(card);
-        player_index += 1;
-    }
-    for player in players {
-        player.cards.sort();
-    }
-    assert_eq!(deck.cards.len(), 0);
-}
+    /// npc turn, decided by the current Player's BotKind (Basic if they are not a bot), which
+    /// only gets to see their PlayerView.
+    pub fn get_npc_turn(&self) -> Result<Action, AiInputError> {
+        let mut bot = match self.current_player().controller {
+            Controller::Bot(kind) => kind,
+            Controller::Human | Controller::Remote => BotKind::default(),
+        };
+        self.bot_turn(&mut bot)
+    }
 
-/// Used to identify the player who was dealt the Three Of Clubs.
-/// The game can only begin with the player that has the Three of Clubs.
-fn find_player_with_three_of_clubs(players: &[Player; NUM_PLAYERS]) -> usize {
-    for (index, player) in players.iter().enumerate() {
-        if player.cards.contains(&THREE_OF_CLUBS) {
-            return index;
-        }
+    /// The Action this Bot chooses for the current Player, from their PlayerView alone.
+    pub fn bot_turn<B: Bot + ?Sized>(&self, bot: &mut B) -> Result<Action, AiInputError> {
+        bot.choose_action(&self.view(self.current_seat()))
     }
-    unreachable!();
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::card::THREE_OF_CLUBS;
     use crate::tests::test_util::vec_card_from_str;
 
     #[test]
     fn test_get_user_input() {
         // let input = "3C";
         let cards = vec_card_from_str("3C 3D 3S 4H 4D 4S");
-        let mut player = Player::default();
-        player.cards = cards;
+        let _player = Player {
+            cards,
+            ..Default::default()
+        };
+    }
+
+    #[test]
+    fn test_with_dealer() {
+        use crate::dealer::prearranged::Prearranged;
+
+        // dealt in order, the Three of Clubs is the very first card, so it goes to the first Seat
+        let game = Game::with_dealer(&mut Prearranged::in_order()).unwrap();
+        assert_eq!(game.current_seat(), Seat::new(0).unwrap());
+        assert!(game.players.iter().all(|p| p.cards.len() == 13));
+        assert!(game.current_player().cards.contains(&THREE_OF_CLUBS));
+
+        // the same seed always deals the same cards
+        let a = Game::with_dealer(&mut UniformShuffle::seeded(42)).unwrap();
+        let b = Game::with_dealer(&mut UniformShuffle::seeded(42)).unwrap();
+        for (a, b) in a.players.iter().zip(b.players.iter()) {
+            assert_eq!(a.cards, b.cards);
+        }
+    }
+
+    /// Deals every Card to the first Seat, which is not fair.
+    struct Greedy;
+
+    impl Dealer for Greedy {
+        fn arrange(&mut self, _: &mut Deck) {}
+
+        fn deal(&mut self, deck: Deck) -> Deal {
+            let mut deal: Deal = Default::default();
+            deal[0] = deck.cards;
+            deal
+        }
+    }
+
+    #[test]
+    fn test_with_unfair_dealer() {
+        assert!(matches!(
+            Game::with_dealer(&mut Greedy),
+            Err(DealError::UnevenDeal)
+        ));
+    }
+
+    #[test]
+    fn test_step_keeps_players_and_state_in_step() {
+        use crate::dealer::prearranged::Prearranged;
+
+        let mut game = Game::with_dealer(&mut Prearranged::in_order()).unwrap();
+        let before = *game.state();
+        let outcome = game.step("3C").unwrap();
+        assert_eq!(outcome.seat, Seat::new(0).unwrap());
+        assert_eq!(outcome.action, "3C".parse().unwrap());
+        assert_eq!(game.played_hands.len(), 1);
+        assert_ne!(*game.state(), before);
+        for player in &game.players {
+            let cards: CardSet = player.cards.iter().collect();
+            assert_eq!(&cards, game.state().hand(player.seat));
+        }
+        assert!(game.step("3C").is_err());
+    }
+
+    #[test]
+    fn test_with_seed() {
+        // the same seed deals the same Cards everywhere, forever
+        let game = Game::with_seed(2024);
+        assert_eq!(
+            game.players[0].to_string(),
+            Game::with_seed(2024).players[0].to_string()
+        );
+        assert_eq!(
+            game.players[0].to_string(),
+            "|3C||4H||5H||7C||9H||JC||JS||QD||KS||AC||AS||2C||2S|"
+        );
+        assert_ne!(
+            game.players[0].to_string(),
+            Game::with_seed(2025).players[0].to_string()
+        );
+    }
+
+    #[test]
+    fn test_from_hands() {
+        let mut hands: Deal = Default::default();
+        for (i, card) in Deck::new().cards.into_iter().enumerate() {
+            // uneven, but every Card exactly once
+            hands[(i / 10).min(NUM_PLAYERS - 1)].push(card);
+        }
+        let leader = Seat::new(0).unwrap();
+        let mut game = Game::from_hands(hands.clone(), leader).unwrap();
+        assert_eq!(game.current_seat(), leader);
+        assert!(!game.current_player().cards.contains(&THREE_OF_CLUBS));
+        let action = game.legal_actions(leader)[0];
+        assert!(game.apply(leader, action).is_ok());
+
+        // a Card missing
+        hands[0].pop();
+        assert!(matches!(
+            Game::from_hands(hands.clone(), leader),
+            Err(DealError::NotAFullDeck)
+        ));
+        // a Card twice
+        let card = hands[1][0];
+        hands[0].push(card);
+        assert!(matches!(
+            Game::from_hands(hands, leader),
+            Err(DealError::NotAFullDeck)
+        ));
+
+        // a full Deck, but a Seat with nothing to play
+        let mut hands = Deal::default();
+        for (i, card) in Deck::new().cards.into_iter().enumerate() {
+            hands[i % (NUM_PLAYERS - 1)].push(card);
+        }
+        assert!(matches!(
+            Game::from_hands(hands, leader),
+            Err(DealError::EmptyHand(seat)) if seat.index() == NUM_PLAYERS - 1
+        ));
+    }
+
+    #[test]
+    fn test_observer() {
+        use crate::dealer::prearranged::Prearranged;
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        let mut game = Game::with_dealer(&mut Prearranged::in_order()).unwrap();
+        let first = game.current_seat();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = events.clone();
+        game.add_observer(move |event: &GameEvent| log.borrow_mut().push(event.clone()));
+
+        let hand: Hand = "3C".parse().unwrap();
+        game.apply(first, Action::Play(hand)).unwrap();
+        game.apply(first.next(), Action::Pass).unwrap();
+        assert_eq!(
+            *events.borrow(),
+            [
+                GameEvent::Dealt,
+                GameEvent::TurnStarted(first),
+                GameEvent::Played(first, hand),
+                GameEvent::TurnStarted(first.next()),
+                GameEvent::Passed(first.next()),
+                GameEvent::TurnStarted(first.next().next()),
+            ]
+        );
+
+        // illegal Actions are not events
+        assert!(game.apply(first, Action::Pass).is_err());
+        assert_eq!(events.borrow().len(), 6);
+    }
+
+    #[test]
+    fn test_apply() {
+        use crate::dealer::prearranged::Prearranged;
+
+        let mut game = Game::with_dealer(&mut Prearranged::in_order()).unwrap();
+        let first = game.current_seat();
+        let play: Action = "3C".parse().unwrap();
+
+        // only the current Seat may act
+        let other = first.next();
+        assert!(matches!(
+            game.apply(other, play),
+            Err(GameStepError::NotYourTurn(seat)) if seat == other
+        ));
+        assert!(game.played_hands.is_empty());
+
+        let outcome = game.apply(first, play).unwrap();
+        assert_eq!(outcome.seat, first);
+        assert_eq!(game.current_seat(), other);
+        assert!(matches!(
+            game.apply(first, Action::Pass),
+            Err(GameStepError::NotYourTurn(_))
+        ));
+        assert!(game.apply(other, Action::Pass).is_ok());
+    }
+
+    #[test]
+    fn test_forfeit() {
+        use crate::dealer::prearranged::Prearranged;
+
+        let mut game = Game::with_dealer(&mut Prearranged::in_order()).unwrap();
+        let first = game.current_seat();
+        game.step("3C").unwrap();
+        let second = game.current_seat();
+        assert!(game.can_undo());
+
+        // a Seat may forfeit when it is not their turn, and only once
+        let last = Seat::new(NUM_PLAYERS - 1).unwrap();
+        game.forfeit(last).unwrap();
+        assert!(matches!(
+            game.forfeit(last),
+            Err(GameStepError::Forfeited(_))
+        ));
+        assert_eq!(game.current_seat(), second);
+        assert!(!game.can_undo());
+
+        // the rest carry on without them, and the forfeit replays
+        game.apply(second, Action::Pass).unwrap();
+        game.apply(second.next(), Action::Pass).unwrap();
+        assert_eq!(game.current_seat(), first);
+        assert!(game.state().is_start_trick());
+        assert_eq!(Game::replay(game.log()).unwrap().state(), game.state());
     }
 }
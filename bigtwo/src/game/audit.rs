@@ -0,0 +1,563 @@
+//! Checks every invariant of a Game, from its log alone or together with a live Game, and
+//! reports every violation rather than stopping at the first like Game::replay does. The rules
+//! are followed step by step independently of GameState, so that Games from remote clients, older
+//! versions of this crate, or a buggy GameState are all held to the same standard.
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::action::Action;
+use crate::card::set::CardSet;
+use crate::card::Card;
+use crate::game::check_player_can_play_hand::check_player_can_play_hand;
+use crate::game::log::{GameLog, LogEntry};
+use crate::game::next_player_id::next_player_id;
+use crate::game::rules::Rules;
+use crate::game::state::GameState;
+use crate::game::{zobrist, Game, PlayHandError, NUM_PLAYERS};
+use crate::hand::Hand;
+use crate::player::seat::{Seat, SeatSet};
+
+/// One broken invariant, and the move it was found at.
+#[derive(Debug)]
+pub struct Violation {
+    /// The entry of the GameLog it was found at: 0 is the deal, 1 the first move, and so on.
+    /// Checks of the position after the last move use the number of moves.
+    pub move_number: usize,
+    pub kind: ViolationKind,
+}
+
+/// The invariants an audit checks.
+#[derive(Debug)]
+pub enum ViolationKind {
+    /// The log does not start with the deal.
+    MissingDeal,
+    /// The deal appears again after the start of the log.
+    UnexpectedDeal,
+    /// The Cards are not exactly one full Deck, these Cards are missing or there more than once.
+    CardsNotConserved {
+        missing: CardSet,
+        duplicated: CardSet,
+    },
+    /// These Cards were played more than once.
+    CardsPlayedTwice(CardSet),
+    /// A move was made after the Game was over.
+    AfterGameOver,
+    /// A Seat moved when it was another Seat's turn.
+    OutOfTurn { expected: Seat, actual: Seat },
+    /// A Seat led when the last Trick was won by another Seat.
+    WrongLeader { expected: Seat, actual: Seat },
+    /// The Action was not allowed at the time.
+    IllegalAction(PlayHandError),
+    /// A Seat that had already forfeited moved, or forfeited again.
+    AlreadyForfeited(Seat),
+    /// A Seat took back an Action it did not take last, or when there was none it could.
+    BadUndo(Seat),
+    /// The declared winner is not who won the Game, None if the Game is not over.
+    WrongWinner {
+        declared: Seat,
+        actual: Option<Seat>,
+    },
+    /// A live Player's Cards do not match the Cards their Seat holds according to the log.
+    PlayerCardsMismatch(Seat),
+    /// A live Game's played Hands do not match the Hands played in its log.
+    PlayedHandsMismatch,
+    /// A live Game's GameState, or its hash, does not match the position its log leads to.
+    StateMismatch,
+}
+
+impl Display for ViolationKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::MissingDeal => write!(f, "the log does not start with the deal"),
+            Self::UnexpectedDeal => write!(f, "the cards are dealt again"),
+            Self::CardsNotConserved {
+                missing,
+                duplicated,
+            } => write!(
+                f,
+                "the cards are not one full deck, missing [{}], duplicated [{}]",
+                missing, duplicated
+            ),
+            Self::CardsPlayedTwice(cards) => write!(f, "[{}] played more than once", cards),
+            Self::AfterGameOver => write!(f, "moved after the game was over"),
+            Self::OutOfTurn { expected, actual } => {
+                write!(f, "seat {} moved on seat {}'s turn", actual, expected)
+            }
+            Self::WrongLeader { expected, actual } => {
+                write!(f, "seat {} led but seat {} won the trick", actual, expected)
+            }
+            Self::IllegalAction(e) => write!(f, "PlayHandError! {}", e),
+            Self::AlreadyForfeited(seat) => write!(f, "seat {} had already forfeited", seat),
+            Self::BadUndo(seat) => write!(f, "seat {} undid an action it cannot", seat),
+            Self::WrongWinner {
+                declared,
+                actual: Some(actual),
+            } => write!(
+                f,
+                "seat {} is declared the winner, seat {} won",
+                declared, actual
+            ),
+            Self::WrongWinner {
+                declared,
+                actual: None,
+            } => write!(
+                f,
+                "seat {} is declared the winner, the game is not over",
+                declared
+            ),
+            Self::PlayerCardsMismatch(seat) => {
+                write!(f, "seat {}'s player has different cards to the log", seat)
+            }
+            Self::PlayedHandsMismatch => write!(f, "the played hands differ from the log"),
+            Self::StateMismatch => write!(f, "the game state differs from the log"),
+        }
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "move {}: {}", self.move_number, self.kind)
+    }
+}
+
+/// Checks every move of the log, and that the declared winner (if any) won, returns every
+/// Violation found in the order they were found. Empty if the log is a valid Game.
+pub fn audit(log: &GameLog, declared_winner: Option<Seat>) -> Vec<Violation> {
+    let (mut violations, followed) = follow(log);
+    if let Some(declared) = declared_winner {
+        let actual = followed.as_ref().and_then(Followed::winner);
+        if actual != Some(declared) {
+            violations.push(Violation {
+                move_number: log.entries().len().saturating_sub(1),
+                kind: ViolationKind::WrongWinner { declared, actual },
+            });
+        }
+    }
+    violations
+}
+
+impl Game {
+    /// Audits the log of this Game, and checks that the Players, the played Hands and the
+    /// GameState all match it. Empty if everything is as it should be.
+    pub fn audit(&self) -> Vec<Violation> {
+        let (mut violations, followed) = follow(self.log());
+        let Some(followed) = followed else {
+            return violations;
+        };
+        let moves = self.log().entries().len() - 1;
+        let mut violation = |kind| {
+            violations.push(Violation {
+                move_number: moves,
+                kind,
+            })
+        };
+
+        let mut held = Vec::new();
+        for (seat, player) in Seat::all().into_iter().zip(&self.players) {
+            let cards: CardSet = player.cards.iter().collect();
+            if player.cards.len() != cards.len() || cards != followed.hands[seat.index()] {
+                violation(ViolationKind::PlayerCardsMismatch(seat));
+            }
+            held.extend(player.cards.iter().copied());
+        }
+        let played: Vec<Hand> = self
+            .log()
+            .actions()
+            .filter_map(|(_, a)| a.hand().copied())
+            .collect();
+        if self.played_hands != played {
+            violation(ViolationKind::PlayedHandsMismatch);
+        }
+        let all = held
+            .iter()
+            .chain(self.played_hands.iter().flat_map(Hand::cards));
+        if let Some(kind) = unconserved(all) {
+            violation(kind);
+        }
+        if !followed.matches(self.state()) {
+            violation(ViolationKind::StateMismatch);
+        }
+        violations
+    }
+}
+
+/// A CardsNotConserved violation, unless these are exactly the Cards of one full Deck.
+fn unconserved<'a, I: IntoIterator<Item = &'a Card>>(cards: I) -> Option<ViolationKind> {
+    let mut seen = CardSet::new();
+    let mut duplicated = CardSet::new();
+    for card in cards {
+        if !seen.insert(*card) {
+            duplicated.insert(*card);
+        }
+    }
+    let missing = CardSet::full().difference(&seen);
+    (!missing.is_empty() || !duplicated.is_empty()).then_some(ViolationKind::CardsNotConserved {
+        missing,
+        duplicated,
+    })
+}
+
+/// Follows the log move by move, returns every Violation, and the position it leads to (None if
+/// there is no deal). Illegal moves are carried out anyway, as if they were allowed, so that
+/// every later move is still checked against what actually happened.
+fn follow(log: &GameLog) -> (Vec<Violation>, Option<Followed>) {
+    let mut violations = Vec::new();
+    let mut entries = log.entries().iter().enumerate();
+    let Some((
+        _,
+        LogEntry::Deal {
+            hands,
+            leader,
+            rules,
+        },
+    )) = entries.next()
+    else {
+        violations.push(Violation {
+            move_number: 0,
+            kind: ViolationKind::MissingDeal,
+        });
+        return (violations, None);
+    };
+    if let Some(kind) = unconserved(hands.iter().flatten()) {
+        violations.push(Violation {
+            move_number: 0,
+            kind,
+        });
+    }
+
+    let mut followed = Followed::new(
+        hands.each_ref().map(|hand| hand.iter().collect()),
+        *leader,
+        *rules,
+    );
+    // the position before each Action that can still be taken back, and who took it
+    let mut undoable: Vec<(Seat, Followed)> = Vec::new();
+    for (move_number, entry) in entries {
+        let mut violation = |kind| {
+            violations.push(Violation { move_number, kind });
+        };
+        match entry {
+            // an undo may take back the move that ended the Game
+            LogEntry::Undo { seat } => match undoable.pop() {
+                Some((taken_by, before)) => {
+                    if taken_by != *seat {
+                        violation(ViolationKind::BadUndo(*seat));
+                    }
+                    followed = before;
+                }
+                None => violation(ViolationKind::BadUndo(*seat)),
+            },
+            _ if followed.is_over() => violation(ViolationKind::AfterGameOver),
+            LogEntry::Deal { .. } => violation(ViolationKind::UnexpectedDeal),
+            LogEntry::Forfeit { seat } if followed.forfeited.contains(seat) => {
+                violation(ViolationKind::AlreadyForfeited(*seat))
+            }
+            // a forfeit cannot be taken back, nor anything before it
+            LogEntry::Forfeit { seat } => {
+                followed.forfeit(*seat);
+                undoable.clear();
+            }
+            LogEntry::Action { seat, .. } if followed.forfeited.contains(seat) => {
+                violation(ViolationKind::AlreadyForfeited(*seat))
+            }
+            LogEntry::Action { seat, action } => {
+                undoable.push((*seat, followed.clone()));
+                let expected = followed.current_seat;
+                if *seat != expected && followed.is_start_trick && followed.last_play.is_some() {
+                    violation(ViolationKind::WrongLeader {
+                        expected,
+                        actual: *seat,
+                    });
+                } else if *seat != expected {
+                    violation(ViolationKind::OutOfTurn {
+                        expected,
+                        actual: *seat,
+                    });
+                }
+                if let Err(e) = check_player_can_play_hand(
+                    followed.last_play.as_ref().map(|(_, hand)| hand),
+                    &followed.hands[seat.index()],
+                    action,
+                    followed.is_start_trick,
+                    &followed.rules,
+                ) {
+                    violation(ViolationKind::IllegalAction(e));
+                }
+                if let Some(twice) = followed.take(*seat, action) {
+                    violation(ViolationKind::CardsPlayedTwice(twice));
+                }
+            }
+        }
+    }
+    (violations, Some(followed))
+}
+
+/// The position according to the log, the same as a GameState but kept without its checks.
+#[derive(Clone)]
+struct Followed {
+    hands: [CardSet; NUM_PLAYERS],
+    played: CardSet,
+    current_seat: Seat,
+    last_play: Option<(Seat, Hand)>,
+    passed: SeatSet,
+    forfeited: SeatSet,
+    is_start_trick: bool,
+    rules: Rules,
+}
+
+impl Followed {
+    fn new(hands: [CardSet; NUM_PLAYERS], leader: Seat, rules: Rules) -> Self {
+        Followed {
+            hands,
+            played: CardSet::new(),
+            current_seat: leader,
+            last_play: None,
+            passed: SeatSet::new(),
+            forfeited: SeatSet::new(),
+            is_start_trick: true,
+            rules,
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        self.hands.iter().any(CardSet::is_empty) || self.forfeited.len() >= NUM_PLAYERS - 1
+    }
+
+    /// The Seat that emptied its hand, or the last Seat that did not forfeit.
+    fn winner(&self) -> Option<Seat> {
+        let mut seats = Seat::all().into_iter();
+        if !self.is_over() {
+            None
+        } else if self.forfeited.len() >= NUM_PLAYERS - 1 {
+            seats.find(|seat| !self.forfeited.contains(seat))
+        } else {
+            seats.find(|seat| self.hands[seat.index()].is_empty())
+        }
+    }
+
+    /// The Seat takes the Action, whether or not it was allowed to. Returns the Cards it played
+    /// that had already been played, if any.
+    fn take(&mut self, seat: Seat, action: &Action) -> Option<CardSet> {
+        let mut twice = None;
+        match action {
+            Action::Pass => {
+                self.passed.insert(seat);
+            }
+            Action::Play(hand) => {
+                let cards = CardSet::from_hand(hand);
+                let already = cards.intersection(&self.played);
+                twice = (!already.is_empty()).then_some(already);
+                self.hands[seat.index()] = self.hands[seat.index()].difference(&cards);
+                self.played = self.played.union(&cards);
+                self.last_play = Some((seat, *hand));
+            }
+        }
+        // even everybody passing must move the turn on
+        let out = self.passed.union(&self.forfeited);
+        self.current_seat = next_player_id(seat, &out).unwrap_or(seat.next());
+        self.is_start_trick = self.passed.len() + self.forfeited.len() == NUM_PLAYERS - 1;
+        if self.is_start_trick {
+            self.passed.clear();
+        }
+        twice
+    }
+
+    fn forfeit(&mut self, seat: Seat) {
+        self.forfeited.insert(seat);
+        self.passed.remove(&seat);
+        if self.is_over() {
+            return;
+        }
+        let out = self.passed.union(&self.forfeited);
+        if seat == self.current_seat {
+            self.current_seat = next_player_id(seat, &out).unwrap_or(seat.next());
+        }
+        self.is_start_trick = self.is_start_trick || out.len() == NUM_PLAYERS - 1;
+        if self.is_start_trick {
+            self.passed.clear();
+        }
+    }
+
+    /// True if the GameState is this position, with an up to date hash.
+    fn matches(&self, state: &GameState) -> bool {
+        state.hands() == &self.hands
+            && state.current_seat() == self.current_seat
+            && state.last_play() == self.last_play
+            && state.passed() == self.passed
+            && state.forfeited() == self.forfeited
+            && state.is_start_trick() == self.is_start_trick
+            && state.rules() == &self.rules
+            && state.zobrist() == zobrist::hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dealer::prearranged::Prearranged;
+    use crate::dealer::{Deal, Dealer};
+    use crate::deck::Deck;
+    use crate::tests::test_util::seat;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    fn play(hand: &str) -> Action {
+        hand.parse().unwrap()
+    }
+
+    /// A log of a Game dealt in order, with these moves.
+    fn log(moves: &[(usize, &str)]) -> GameLog {
+        let deal = Prearranged::in_order().deal(Deck::new());
+        let mut log = GameLog::new(deal, seat(0), Rules::default());
+        for (index, action) in moves {
+            log.push(LogEntry::Action {
+                seat: seat(*index),
+                action: play(action),
+            });
+        }
+        log
+    }
+
+    fn kinds(violations: &[Violation]) -> Vec<(usize, &ViolationKind)> {
+        violations
+            .iter()
+            .map(|v| (v.move_number, &v.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_games() {
+        let mut game = Game::with_seed(21);
+        game.forfeit(seat(2)).unwrap();
+        while game.is_on() {
+            let action = game.get_npc_turn().unwrap();
+            game.apply(game.current_seat(), action).unwrap();
+        }
+        assert!(game.audit().is_empty());
+        let winner = game.state().standings()[0].seat;
+        assert!(audit(game.log(), Some(winner)).is_empty());
+        assert_eq!(audit(game.log(), Some(seat(2))).len(), 1);
+    }
+
+    #[test]
+    fn test_followed_matches_game_state() {
+        // bot Games where now and then somebody forfeits, whether or not it is their turn
+        for seed in 0..20 {
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            let mut game = Game::with_seed(seed);
+            while game.is_on() {
+                if rng.gen_ratio(1, 20) {
+                    let seat = seat(rng.gen_range(0..NUM_PLAYERS));
+                    if game.forfeit(seat).is_err() {
+                        continue;
+                    }
+                } else {
+                    let action = game.get_npc_turn().unwrap();
+                    game.apply(game.current_seat(), action).unwrap();
+                }
+                let (violations, followed) = follow(game.log());
+                assert!(violations.is_empty(), "seed {}: {:?}", seed, violations);
+                assert!(followed.unwrap().matches(game.state()), "seed {}", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_violation_is_reported() {
+        // dealt in order, each Seat holds one Suit. Seat 0 opens, Seat 2 jumps the queue, Seat 3
+        // plays too low, Seat 0 plays a Card it does not hold
+        let violations = audit(&log(&[(0, "3C"), (2, "5H"), (3, "4S"), (0, "3D")]), None);
+        assert!(matches!(
+            kinds(&violations)[..],
+            [
+                (2, ViolationKind::OutOfTurn { expected, actual }),
+                (3, ViolationKind::IllegalAction(PlayHandError::TooLow)),
+                (4, ViolationKind::IllegalAction(PlayHandError::StolenCards)),
+            ] if *expected == seat(1) && *actual == seat(2)
+        ));
+        assert_eq!(
+            violations[0].to_string(),
+            "move 2: seat 2 moved on seat 1's turn"
+        );
+
+        // the trick is won by Seat 0, but Seat 1 leads
+        let violations = audit(
+            &log(&[(0, "3C"), (1, ""), (2, ""), (3, ""), (1, "3D")]),
+            None,
+        );
+        assert!(matches!(
+            kinds(&violations)[..],
+            [(5, ViolationKind::WrongLeader { expected, .. })] if *expected == seat(0)
+        ));
+    }
+
+    #[test]
+    fn test_undo() {
+        let mut game = Game::with_seed(21);
+        game.step("3C").unwrap();
+        game.undo().unwrap();
+        game.step("3C").unwrap();
+        assert!(game.audit().is_empty());
+
+        // Seat 1 takes back Seat 0's opening, then Seat 0 takes back nothing
+        let mut log = log(&[(0, "3C")]);
+        for index in [1, 0] {
+            log.push(LogEntry::Undo { seat: seat(index) });
+        }
+        assert!(matches!(
+            kinds(&audit(&log, None))[..],
+            [
+                (2, ViolationKind::BadUndo(first)),
+                (3, ViolationKind::BadUndo(second)),
+            ] if first.index() == 1 && second.index() == 0
+        ));
+    }
+
+    #[test]
+    fn test_cards_are_conserved() {
+        // the Three of Clubs is dealt twice, the Four of Clubs not at all
+        let mut deal: Deal = Prearranged::in_order().deal(Deck::new());
+        let four = deal[0]
+            .iter()
+            .position(|card| card.to_string() == "4C")
+            .unwrap();
+        deal[0].remove(four);
+        deal[1].push("3C".parse().unwrap());
+        let mut log = GameLog::new(deal, seat(0), Rules::default());
+        for (index, action) in [(0, "3C"), (1, "3C")] {
+            log.push(LogEntry::Action {
+                seat: seat(index),
+                action: play(action),
+            });
+        }
+        let violations = audit(&log, None);
+        assert!(matches!(
+            kinds(&violations)[..],
+            [
+                (0, ViolationKind::CardsNotConserved { missing, duplicated }),
+                (2, ViolationKind::CardsPlayedTwice(_)),
+            ] if missing.len() == 1 && duplicated.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_tampered_live_game() {
+        let mut game = Game::with_seed(21);
+        game.step("3C").unwrap();
+        assert!(game.audit().is_empty());
+
+        game.players[1].cards.pop();
+        game.played_hands.clear();
+        let violations = game.audit();
+        assert!(matches!(
+            kinds(&violations)[..],
+            [
+                (1, ViolationKind::PlayerCardsMismatch(seat)),
+                (1, ViolationKind::PlayedHandsMismatch),
+                (1, ViolationKind::CardsNotConserved { .. }),
+            ] if seat.index() == 1
+        ));
+    }
+}
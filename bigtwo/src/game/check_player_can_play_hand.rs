@@ -1,10 +1,12 @@
 //! Checks if a specified Player can actually play the Hand they are attempting to play.
 
+use crate::action::Action;
+use crate::card::set::CardSet;
 use crate::card::THREE_OF_CLUBS;
+use crate::game::rules::Rules;
 use crate::hand::{order::order, Hand};
-use crate::player::Player;
 
-use std::fmt::Display;
+use core::fmt::Display;
 
 /// Represents the different ways a Player's attempted Hand is not playable
 #[derive(Debug)]
@@ -27,7 +29,7 @@ pub enum PlayHandError {
 }
 
 impl Display for PlayHandError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             Self::NotThreeOfClubsToStartGame => write!(f, "must start game using Three of Clubs"),
             Self::PassedOnTrickStart => write!(f, "cannot pass at the start of a trick"),
@@ -38,21 +40,33 @@ impl Display for PlayHandError {
     }
 }
 
-/// Checks if a specified Player can actually play the Hand they are attempting to play.
-/// Returns () if the Hand is playable, otherwise returns a specific PlayHandError.
+/// Checks if a Player holding these Cards can actually take the Action they are attempting to take.
+/// Returns () if the Action is allowed, otherwise returns a specific PlayHandError.
 pub fn check_player_can_play_hand(
     last_played_hand: Option<&Hand>,
-    player: &Player,
-    attempt: &Hand,
+    cards: &CardSet,
+    attempt: &Action,
     is_start_trick: bool,
+    rules: &Rules,
 ) -> Result<(), PlayHandError> {
-    if let Hand::Pass = attempt {
-        if !is_start_trick {
-            Ok(())
-        } else {
-            Err(PlayHandError::PassedOnTrickStart)
+    match attempt {
+        Action::Pass if is_start_trick => Err(PlayHandError::PassedOnTrickStart),
+        Action::Pass => Ok(()),
+        Action::Play(hand) => {
+            check_player_can_play(last_played_hand, cards, hand, is_start_trick, rules)
         }
-    } else if !player.has_cards(attempt) {
+    }
+}
+
+/// Checks if a Player holding these Cards can actually play the Hand they are attempting to play.
+fn check_player_can_play(
+    last_played_hand: Option<&Hand>,
+    cards: &CardSet,
+    attempt: &Hand,
+    is_start_trick: bool,
+    rules: &Rules,
+) -> Result<(), PlayHandError> {
+    if !cards.contains_hand(attempt) {
         // player may only play cards they possess
         Err(PlayHandError::StolenCards)
     } else if let Some(last) = last_played_hand {
@@ -63,15 +77,18 @@ pub fn check_player_can_play_hand(
             // use non-derived custom order to decide if Hand is playable on top of
             let ordering = order(last, attempt);
             match ordering {
-                Some(std::cmp::Ordering::Greater) => Err(PlayHandError::TooLow),
+                Some(core::cmp::Ordering::Greater) => Err(PlayHandError::TooLow),
                 None => Err(PlayHandError::NotMatching),
                 _ => Ok(()),
             }
         }
     } else {
-        // must play three of clubs to start game
-        let c = attempt.cards().last().unwrap();
-        if c == &THREE_OF_CLUBS {
+        // must play three of clubs to start game, it is the lowest card so it is always last.
+        // a Game set up with another leader, or with Rules that say so, may start with anything.
+        if !rules.three_of_clubs_opens
+            || !cards.contains(&THREE_OF_CLUBS)
+            || attempt.cards().last() == Some(&THREE_OF_CLUBS)
+        {
             Ok(())
         } else {
             Err(PlayHandError::NotThreeOfClubsToStartGame)
@@ -87,37 +104,48 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
+    use crate::game::position::Position;
     use crate::tests::test_util::vec_card_from_str;
 
     #[test]
     fn test_check_player_can_play_hand() {
         // start game w/ no hand to beat
-        let player = Player {
-            cards: vec_card_from_str("3C 3S"),
-        };
+        let cards: CardSet = vec_card_from_str("3C 3S").into();
 
         let hand: Hand = "3S".parse().unwrap();
-        let res = check_player_can_play_hand(None, &player, &hand, true);
+        let res = check_player_can_play_hand(None, &cards, &hand.into(), true, &Rules::default());
         assert!(matches!(
             res,
             Err(PlayHandError::NotThreeOfClubsToStartGame)
         ));
 
         let hand: Hand = "3C".parse().unwrap();
-        let res = check_player_can_play_hand(None, &player, &hand, true);
-        assert!(matches!(res, Ok(_)));
+        let res = check_player_can_play_hand(None, &cards, &hand.into(), true, &Rules::default());
+        assert!(res.is_ok());
+
+        // a leader without the Three of Clubs may start with anything, but may not pass
+        let leader: CardSet = vec_card_from_str("3S").into();
+        let hand: Hand = "3S".parse().unwrap();
+        let res = check_player_can_play_hand(None, &leader, &hand.into(), true, &Rules::default());
+        assert!(res.is_ok());
+        let res = check_player_can_play_hand(None, &leader, &Action::Pass, true, &Rules::default());
+        assert!(matches!(res, Err(PlayHandError::PassedOnTrickStart)));
 
         // new trick begins with a Three of Clubs (ostensibly by player 0),
         let hand_to_beat = Hand::from_str("3C").unwrap();
 
         // player has a few cards
-        let cards = vec_card_from_str("3D 3S 4H 4D 4S");
-        let mut player = Player::default();
-        player.cards = cards;
+        let mut cards: CardSet = vec_card_from_str("3D 3S 4H 4D 4S").into();
 
         // plays a Three of Spades
         let hand: Hand = "3S".parse().unwrap();
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, false);
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &hand.into(),
+            false,
+            &Rules::default(),
+        );
         assert!(matches!(res, Ok(())));
 
         // update hand
@@ -125,51 +153,145 @@ mod tests {
 
         // incorrectly plays a Three of Diamonds, reject
         let hand: Hand = "3D".parse().unwrap();
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, false);
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &hand.into(),
+            false,
+            &Rules::default(),
+        );
         assert!(matches!(res, Err(PlayHandError::TooLow)));
 
         // incorrectly plays a Pair of Fours, reject
         let hand: Hand = "4H 4D".parse().unwrap();
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, false);
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &hand.into(),
+            false,
+            &Rules::default(),
+        );
         assert!(matches!(res, Err(PlayHandError::NotMatching)));
 
         // incorrectly plays cards they don't have
         let hand: Hand = "2S".parse().unwrap();
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, false);
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &hand.into(),
+            false,
+            &Rules::default(),
+        );
         assert!(matches!(res, Err(PlayHandError::StolenCards)));
 
         // passes
-        let hand: Hand = "".parse().unwrap();
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, false);
-        assert!(matches!(res, Ok(_)));
+        let action = Action::Pass;
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &action,
+            false,
+            &Rules::default(),
+        );
+        assert!(res.is_ok());
 
         // loses
         let hand_to_beat: Hand = "7D 6H 5C 4H 3D".parse().unwrap();
-        let cards = vec_card_from_str("7C 6D 5H 4D 3S");
-        let hand = Hand::try_from_cards(&cards[..]).unwrap();
-        player.cards = cards;
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, false);
+        let played = vec_card_from_str("7C 6D 5H 4D 3S");
+        let hand = Hand::try_from_cards(&played[..]).unwrap();
+        cards = played.into();
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &hand.into(),
+            false,
+            &Rules::default(),
+        );
         assert!(matches!(res, Err(PlayHandError::TooLow)));
 
         // wins
-        let cards = vec_card_from_str("7S 6D 5H 4D 3S");
-        let hand = Hand::try_from_cards(&cards[..]).unwrap();
-        player.cards = cards;
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, false);
-        assert!(matches!(res, Ok(_)));
+        let played = vec_card_from_str("7S 6D 5H 4D 3S");
+        let hand = Hand::try_from_cards(&played[..]).unwrap();
+        cards = played.into();
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &hand.into(),
+            false,
+            &Rules::default(),
+        );
+        assert!(res.is_ok());
 
         // start new trick, can play anything
-        let cards = vec_card_from_str("3H");
-        let hand = Hand::try_from_cards(&cards[..]).unwrap();
-        player.cards = cards;
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, true);
-        assert!(matches!(res, Ok(_)));
+        let played = vec_card_from_str("3H");
+        let hand = Hand::try_from_cards(&played[..]).unwrap();
+        cards = played.into();
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &hand.into(),
+            true,
+            &Rules::default(),
+        );
+        assert!(res.is_ok());
 
         // start new trick, cannot pass
-        let cards = vec![];
-        let hand = Hand::try_from_cards(&cards[..]).unwrap();
-        player.cards = cards;
-        let res = check_player_can_play_hand(Some(&hand_to_beat), &player, &hand, true);
+        let action = Action::Pass;
+        cards = CardSet::new();
+        let res = check_player_can_play_hand(
+            Some(&hand_to_beat),
+            &cards,
+            &action,
+            true,
+            &Rules::default(),
+        );
         assert!(matches!(res, Err(PlayHandError::PassedOnTrickStart)));
     }
+
+    /// Checks the Action for the Seat to move in a Position, see game::position.
+    fn check(position: &str, action: &str) -> Result<(), PlayHandError> {
+        let position: Position = position.parse().unwrap();
+        check_player_can_play_hand(
+            position.last_hand(),
+            position.cards(position.to_move).unwrap(),
+            &action.parse().unwrap(),
+            position.is_start_trick,
+            &position.rules,
+        )
+    }
+
+    #[test]
+    fn test_positions() {
+        let opening = "3C3S/13/13/13 - - - 0* standard";
+        assert!(matches!(
+            check(opening, "3S"),
+            Err(PlayHandError::NotThreeOfClubsToStartGame)
+        ));
+        assert!(check(&opening.replace("standard", "free-opening"), "3S").is_ok());
+
+        let pairs = "13/4H4D4S3D/13/13 2:3S3H - - 1 standard";
+        assert!(check(pairs, "4H 4D").is_ok());
+        assert!(check(pairs, "pass").is_ok());
+        assert!(matches!(
+            check(pairs, "4S"),
+            Err(PlayHandError::NotMatching)
+        ));
+        assert!(matches!(
+            check(pairs, "2S 2H"),
+            Err(PlayHandError::StolenCards)
+        ));
+
+        let straights = "13/7C6D5H4D3S/13/13 0:7D6H5C4H3D 3 - 1 standard";
+        assert!(matches!(
+            check(straights, "7C 6D 5H 4D 3S"),
+            Err(PlayHandError::TooLow)
+        ));
+        // everybody else passed, so Seat 1 leads the next Trick
+        let leading = straights.replace(" 3 - 1 ", " - - 1* ");
+        assert!(check(&leading, "3S").is_ok());
+        assert!(matches!(
+            check(&leading, "pass"),
+            Err(PlayHandError::PassedOnTrickStart)
+        ));
+    }
 }
@@ -0,0 +1,350 @@
+//! Time controls, so that one slow Player cannot stall everybody else. The Game reads the time
+//! from a Clock it is given, so tests (and replays) can control time exactly.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::Cell;
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::game::observer::GameEvent;
+use crate::game::save::SavedTimer;
+use crate::game::{Game, GameStepError, NUM_PLAYERS};
+use crate::player::seat::Seat;
+
+/// Tells the time, as the time since some fixed start, which only has to stay the same for the
+/// life of a Game.
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+
+impl<C: Clock + ?Sized> Clock for Box<C> {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// The time on the system's monotonic clock, since this Clock was made.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock(std::time::Instant);
+
+#[cfg(feature = "std")]
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock(std::time::Instant::now())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.0.elapsed()
+    }
+}
+
+/// A Clock that only moves when told to, e.g. for tests. Clones share the same time, so keep a
+/// clone to move the time of a Game that was given this Clock.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock(Rc<Cell<Duration>>);
+
+impl ManualClock {
+    /// A Clock stopped at zero.
+    pub fn new() -> Self {
+        ManualClock::default()
+    }
+
+    /// Moves the time forward.
+    pub fn advance(&self, by: Duration) {
+        self.0.set(self.0.get() + by);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.0.get()
+    }
+}
+
+/// What happens to a Seat that runs out of time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimeoutPolicy {
+    /// They pass, or play their lowest Hand when they are not allowed to pass.
+    #[default]
+    AutoPass,
+    /// They play the lowest Hand they are allowed to, or pass if they have nothing that beats
+    /// the last Hand.
+    AutoPlayLowest,
+    /// They forfeit the Game, which carries on without them.
+    Forfeit,
+}
+
+/// How much time each Seat has to take its Actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TimeControl {
+    /// The time for each turn, it is not carried over to the next turn when it is not all used.
+    pub per_turn: Duration,
+    /// Extra time for the whole Game, used up once a turn takes longer than per_turn.
+    pub bank: Option<Duration>,
+    /// What happens once the turn's time and the bank have both run out.
+    pub on_timeout: TimeoutPolicy,
+}
+
+impl TimeControl {
+    /// This much time for each turn, with no bank, passing on timeout.
+    pub fn per_turn(per_turn: Duration) -> Self {
+        TimeControl {
+            per_turn,
+            bank: None,
+            on_timeout: TimeoutPolicy::default(),
+        }
+    }
+}
+
+/// The TimeControl of a Game, and what is left of each Seat's time.
+pub(crate) struct Timer {
+    control: TimeControl,
+    clock: Box<dyn Clock>,
+    /// When the current turn started.
+    turn_started: Duration,
+    /// How long the current turn had taken before the Game was saved, see Game::from_saved.
+    earlier: Duration,
+    /// What is left of each Seat's bank, at the index of that Seat.
+    banks: [Duration; NUM_PLAYERS],
+}
+
+impl core::fmt::Debug for Timer {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Timer")
+            .field("control", &self.control)
+            .field("turn_started", &self.turn_started)
+            .field("earlier", &self.earlier)
+            .field("banks", &self.banks)
+            .finish()
+    }
+}
+
+impl Timer {
+    /// Carries on where a saved Timer left off, on this Clock.
+    pub(crate) fn from_saved(saved: SavedTimer, clock: Box<dyn Clock>) -> Self {
+        Timer {
+            control: saved.control,
+            turn_started: clock.now(),
+            earlier: saved.elapsed,
+            clock,
+            banks: saved.banks,
+        }
+    }
+
+    /// What is left of the time, to carry on with later.
+    pub(crate) fn to_saved(&self) -> SavedTimer {
+        SavedTimer {
+            control: self.control,
+            banks: self.banks,
+            elapsed: self.elapsed(),
+        }
+    }
+
+    /// How long the current turn has taken so far.
+    fn elapsed(&self) -> Duration {
+        self.clock.now().saturating_sub(self.turn_started) + self.earlier
+    }
+
+    /// How much time the current Seat has left.
+    fn time_left(&self, seat: Seat) -> Duration {
+        (self.control.per_turn + self.banks[seat.index()]).saturating_sub(self.elapsed())
+    }
+
+    /// Takes any time over per_turn out of the Seat's bank, and starts the next turn.
+    pub(crate) fn end_turn(&mut self, seat: Seat) {
+        let over = self.elapsed().saturating_sub(self.control.per_turn);
+        let bank = &mut self.banks[seat.index()];
+        *bank = bank.saturating_sub(over);
+        self.restart_turn();
+    }
+
+    /// Starts the current turn again, from now.
+    pub(crate) fn restart_turn(&mut self) {
+        self.turn_started = self.clock.now();
+        self.earlier = Duration::ZERO;
+    }
+}
+
+impl Game {
+    /// Puts the Game on the clock, starting the current turn now, with every Seat's bank full.
+    pub fn set_time_control<C: Clock + 'static>(&mut self, control: TimeControl, clock: C) {
+        let turn_started = clock.now();
+        self.timer = Some(Timer {
+            control,
+            clock: Box::new(clock),
+            turn_started,
+            earlier: Duration::ZERO,
+            banks: [control.bank.unwrap_or_default(); NUM_PLAYERS],
+        });
+    }
+
+    /// The TimeControl, None if the Game is not on the clock.
+    pub fn time_control(&self) -> Option<&TimeControl> {
+        self.timer.as_ref().map(|timer| &timer.control)
+    }
+
+    /// How much time this Seat has left, counting its bank. For the current Seat this is until
+    /// it times out, for everybody else it is what they will have when their turn comes. None if
+    /// the Game is not on the clock.
+    pub fn time_left(&self, seat: Seat) -> Option<Duration> {
+        let timer = self.timer.as_ref()?;
+        if seat == self.current_seat() {
+            Some(timer.time_left(seat))
+        } else {
+            Some(timer.control.per_turn + timer.banks[seat.index()])
+        }
+    }
+
+    /// What is left of this Seat's bank, None if the Game is not on the clock.
+    pub fn bank(&self, seat: Seat) -> Option<Duration> {
+        self.timer.as_ref().map(|timer| timer.banks[seat.index()])
+    }
+
+    /// True if the current Seat has run out of time, see Game::enforce_time_control.
+    pub fn is_out_of_time(&self) -> bool {
+        self.is_on()
+            && self
+                .timer
+                .as_ref()
+                .is_some_and(|timer| timer.time_left(self.current_seat()).is_zero())
+    }
+
+    /// Applies the TimeoutPolicy if the current Seat has run out of time, and returns that Seat.
+    /// Game::apply refuses Actions from a Seat that is out of time, so whoever runs the Game
+    /// should call this whenever a turn may have run out, e.g. on a timer of their own.
+    pub fn enforce_time_control(&mut self) -> Result<Option<Seat>, GameStepError> {
+        if !self.is_out_of_time() {
+            return Ok(None);
+        }
+        let seat = self.current_seat();
+        let policy = self.time_control().map(|control| control.on_timeout);
+        self.notify(&GameEvent::TimedOut(seat));
+        let actions = self.legal_actions(seat);
+        let lowest = actions.iter().find(|action| action.hand().is_some());
+        match policy {
+            Some(TimeoutPolicy::Forfeit) => self.forfeit(seat)?,
+            Some(TimeoutPolicy::AutoPass) if actions.first() == Some(&Action::Pass) => {
+                self.advance(seat, Action::Pass)?;
+            }
+            _ => {
+                // a Seat that may not pass always has a Hand to play
+                let action = lowest.copied().unwrap_or(Action::Pass);
+                self.advance(seat, action)?;
+            }
+        }
+        self.undone.clear();
+        Ok(Some(seat))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tests::test_util::in_order_game;
+
+    fn seconds(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    fn game_on_the_clock(control: TimeControl) -> (Game, ManualClock) {
+        let mut game = in_order_game(&[]);
+        let clock = ManualClock::new();
+        game.set_time_control(control, clock.clone());
+        (game, clock)
+    }
+
+    #[test]
+    fn test_bank() {
+        let control = TimeControl {
+            bank: Some(seconds(10)),
+            ..TimeControl::per_turn(seconds(5))
+        };
+        let (mut game, clock) = game_on_the_clock(control);
+        let first = game.current_seat();
+        assert_eq!(game.time_left(first), Some(seconds(15)));
+
+        // going over the turn's time eats into the bank
+        clock.advance(seconds(8));
+        assert_eq!(game.time_left(first), Some(seconds(7)));
+        game.step("3C").unwrap();
+        assert_eq!(game.bank(first), Some(seconds(7)));
+        assert_eq!(game.time_left(first), Some(seconds(12)));
+
+        // the next Seat's turn starts when the last one ends
+        let second = game.current_seat();
+        assert_eq!(game.time_left(second), Some(seconds(15)));
+        clock.advance(seconds(4));
+        game.apply(second, Action::Pass).unwrap();
+        assert_eq!(game.bank(second), Some(seconds(10)));
+    }
+
+    #[test]
+    fn test_timeout_policies() {
+        for (policy, expected) in [
+            (TimeoutPolicy::AutoPass, Some(Action::Pass)),
+            (TimeoutPolicy::AutoPlayLowest, Some("3D".parse().unwrap())),
+            (TimeoutPolicy::Forfeit, None),
+        ] {
+            let control = TimeControl {
+                on_timeout: policy,
+                ..TimeControl::per_turn(seconds(30))
+            };
+            let (mut game, clock) = game_on_the_clock(control);
+            game.step("3C").unwrap();
+            let slow = game.current_seat();
+
+            // nothing happens until the time is up
+            clock.advance(seconds(29));
+            assert_eq!(game.enforce_time_control().unwrap(), None);
+            clock.advance(seconds(1));
+            assert!(game.is_out_of_time());
+            assert!(matches!(
+                game.apply(slow, Action::Pass),
+                Err(GameStepError::OutOfTime(seat)) if seat == slow
+            ));
+
+            assert_eq!(game.enforce_time_control().unwrap(), Some(slow));
+            assert_ne!(game.current_seat(), slow);
+            assert_eq!(
+                game.log().actions().nth(1).map(|(_, action)| action),
+                expected
+            );
+            assert_eq!(game.state().forfeited().contains(&slow), expected.is_none());
+            assert!(!game.is_out_of_time());
+        }
+    }
+
+    #[test]
+    fn test_no_undo_on_the_clock() {
+        let mut game = in_order_game(&["3C", "4D"]);
+        game.undo().unwrap();
+        let clock = ManualClock::new();
+        game.set_time_control(TimeControl::per_turn(seconds(30)), clock.clone());
+
+        // the Seat that ran out of time cannot take its turn again with a fresh clock
+        clock.advance(seconds(30));
+        assert!(game.is_out_of_time());
+        assert!(!game.can_undo() && !game.can_redo());
+        assert_eq!(game.undo(), None);
+        assert!(game.redo().is_none());
+        assert!(game.is_out_of_time());
+        assert_eq!(game.log().actions().count(), 1);
+    }
+
+    #[test]
+    fn test_auto_pass_leads_when_it_must() {
+        let (mut game, clock) = game_on_the_clock(TimeControl::per_turn(seconds(1)));
+        clock.advance(seconds(1));
+        assert!(game.enforce_time_control().unwrap().is_some());
+        // the leader may not pass, and must open with the Three of Clubs
+        assert_eq!(game.played_hands, ["3C".parse().unwrap()]);
+    }
+}
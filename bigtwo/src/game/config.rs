@@ -0,0 +1,357 @@
+//! Sets up a Game: who sits where, the Rules, the deal, the clock and who is watching. The CLI,
+//! tests, simulations and servers each want something slightly different, so everything has a
+//! default and GameConfig::build checks that the choices make sense together.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::dealer::{validate_deal, Deal, DealError};
+use crate::game::clock::{Clock, TimeControl};
+use crate::game::observer::GameObserver;
+use crate::game::rules::Rules;
+use crate::game::{Game, NUM_PLAYERS};
+use crate::player::controller::{BotKind, Controller};
+use crate::player::seat::Seat;
+
+/// Represents the ways a GameConfig cannot make a Game.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Only NUM_PLAYERS players are supported, not this many.
+    UnsupportedPlayerCount(usize),
+    /// Both a seed and a deal were given, only one of them can decide the Cards.
+    SeedAndDeal,
+    /// A leader was given without a deal, otherwise whoever is dealt the Three of Clubs leads.
+    LeaderWithoutDeal,
+    /// The given deal cannot be played.
+    DealError(DealError),
+    /// Every turn needs some time.
+    NoTimePerTurn,
+    /// Without std there is no randomness to shuffle with, so a seed or a deal must be given.
+    NoSeedOrDeal,
+}
+
+impl From<DealError> for ConfigError {
+    fn from(e: DealError) -> Self {
+        Self::DealError(e)
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedPlayerCount(n) => {
+                write!(f, "{} players are not supported, only {}", n, NUM_PLAYERS)
+            }
+            Self::SeedAndDeal => write!(f, "give either a seed or a deal, not both"),
+            Self::LeaderWithoutDeal => write!(f, "a leader can only be given with a deal"),
+            Self::DealError(e) => write!(f, "DealError! {}", e),
+            Self::NoTimePerTurn => write!(f, "the time per turn must be more than zero"),
+            Self::NoSeedOrDeal => write!(f, "give a seed or a deal to shuffle without std"),
+        }
+    }
+}
+
+/// Builds a Game. By default the first Seat is a Human and the rest are Basic bots, named
+/// "Player 1" to "Player 4", playing by the standard Rules with a fresh shuffle and no clock.
+pub struct GameConfig {
+    num_players: usize,
+    controllers: [Controller; NUM_PLAYERS],
+    names: [Option<String>; NUM_PLAYERS],
+    rules: Rules,
+    seed: Option<u64>,
+    deal: Option<Deal>,
+    leader: Option<Seat>,
+    time_control: Option<(TimeControl, Box<dyn Clock>)>,
+    observers: Vec<Box<dyn GameObserver>>,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            num_players: NUM_PLAYERS,
+            controllers: Seat::all().map(|seat| {
+                if seat.index() == 0 {
+                    Controller::Human
+                } else {
+                    Controller::Bot(BotKind::Basic)
+                }
+            }),
+            names: Default::default(),
+            rules: Rules::default(),
+            seed: None,
+            deal: None,
+            leader: None,
+            time_control: None,
+            observers: Vec::new(),
+        }
+    }
+}
+
+/// Everything but the clock and the observers, which cannot be printed.
+impl core::fmt::Debug for GameConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("GameConfig")
+            .field("num_players", &self.num_players)
+            .field("controllers", &self.controllers)
+            .field("names", &self.names)
+            .field("rules", &self.rules)
+            .field("seed", &self.seed)
+            .field("deal", &self.deal)
+            .field("leader", &self.leader)
+            .field(
+                "time_control",
+                &self.time_control.as_ref().map(|(control, _)| control),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl GameConfig {
+    /// The defaults, see GameConfig.
+    pub fn new() -> Self {
+        GameConfig::default()
+    }
+
+    /// How many Players are at the table, only NUM_PLAYERS is supported for now.
+    pub fn players(mut self, num_players: usize) -> Self {
+        self.num_players = num_players;
+        self
+    }
+
+    /// Who (or what) decides the plays at this Seat.
+    pub fn controller(mut self, seat: Seat, controller: Controller) -> Self {
+        self.controllers[seat.index()] = controller;
+        self
+    }
+
+    /// Who (or what) decides the plays at every Seat, at the index of that Seat.
+    pub fn controllers(mut self, controllers: [Controller; NUM_PLAYERS]) -> Self {
+        self.controllers = controllers;
+        self
+    }
+
+    /// What to call the Player at this Seat.
+    pub fn name<S: Into<String>>(mut self, seat: Seat, name: S) -> Self {
+        self.names[seat.index()] = Some(name.into());
+        self
+    }
+
+    /// The house Rules to play by.
+    pub fn rules(mut self, rules: Rules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Shuffles with this seed, see Game::with_seed.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Deals exactly these Cards, which must be a fair deal of a full Deck unless a leader is
+    /// also given, see Game::from_hands.
+    pub fn deal(mut self, deal: Deal) -> Self {
+        self.deal = Some(deal);
+        self
+    }
+
+    /// This Seat leads, only with an explicit deal.
+    pub fn leader(mut self, leader: Seat) -> Self {
+        self.leader = Some(leader);
+        self
+    }
+
+    /// Puts the Game on this clock, see Game::set_time_control.
+    pub fn time_control<C: Clock + 'static>(mut self, control: TimeControl, clock: C) -> Self {
+        self.time_control = Some((control, Box::new(clock)));
+        self
+    }
+
+    /// Tells this observer about every GameEvent, from the deal on.
+    pub fn observer<O: GameObserver + 'static>(mut self, observer: O) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    /// The Game, or an Error describing the first choice that does not make sense.
+    pub fn build(self) -> Result<Game, ConfigError> {
+        if self.num_players != NUM_PLAYERS {
+            return Err(ConfigError::UnsupportedPlayerCount(self.num_players));
+        }
+        if let Some((control, _)) = &self.time_control {
+            if control.per_turn.is_zero() {
+                return Err(ConfigError::NoTimePerTurn);
+            }
+        }
+        let mut game = match (self.seed, self.deal, self.leader) {
+            (Some(_), Some(_), _) => return Err(ConfigError::SeedAndDeal),
+            (_, None, Some(_)) => return Err(ConfigError::LeaderWithoutDeal),
+            (_, Some(deal), Some(leader)) => Game::from_hands_with_rules(deal, leader, self.rules)?,
+            (_, Some(deal), None) => {
+                validate_deal(&deal)?;
+                Game::from_valid_deal(deal, None, self.rules)
+            }
+            (Some(seed), None, None) => Game::seeded(seed, self.rules),
+            (None, None, None) => shuffled(self.rules)?,
+        };
+
+        for (player, controller) in game.players.iter_mut().zip(self.controllers) {
+            player.controller = controller;
+        }
+        for (player, name) in game.players.iter_mut().zip(self.names) {
+            if let Some(name) = name {
+                player.name = name;
+            }
+        }
+        if let Some((control, clock)) = self.time_control {
+            game.set_time_control(control, clock);
+        }
+        for observer in self.observers {
+            game.add_boxed_observer(observer);
+        }
+        Ok(game)
+    }
+}
+
+/// A Game dealt with a fresh shuffle.
+#[cfg(feature = "std")]
+fn shuffled(rules: Rules) -> Result<Game, ConfigError> {
+    use crate::dealer::shuffle::UniformShuffle;
+    use crate::dealer::Dealer;
+    use crate::deck::Deck;
+
+    let deal = UniformShuffle::new(&mut rand::thread_rng()).deal(Deck::new());
+    Ok(Game::from_valid_deal(deal, None, rules))
+}
+
+#[cfg(not(feature = "std"))]
+fn shuffled(_: Rules) -> Result<Game, ConfigError> {
+    Err(ConfigError::NoSeedOrDeal)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::action::Action;
+    use crate::card::THREE_OF_CLUBS;
+    use crate::dealer::prearranged::Prearranged;
+    use crate::dealer::Dealer;
+    use crate::deck::Deck;
+    use crate::game::clock::ManualClock;
+    use crate::game::observer::GameEvent;
+    use crate::tests::test_util::seat;
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+    use core::time::Duration;
+
+    #[test]
+    fn test_build() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = events.clone();
+        let game = GameConfig::new()
+            .seed(2024)
+            .players(NUM_PLAYERS)
+            .controllers([Controller::Remote; NUM_PLAYERS])
+            .controller(seat(2), Controller::Human)
+            .name(seat(2), "Ada")
+            .time_control(
+                TimeControl::per_turn(Duration::from_secs(30)),
+                ManualClock::new(),
+            )
+            .observer(move |event: &GameEvent| log.borrow_mut().push(event.clone()))
+            .build()
+            .unwrap();
+
+        // the same Cards as any other Game with this seed
+        assert_eq!(game.seed(), Some(2024));
+        assert_eq!(game.state(), Game::with_seed(2024).state());
+        assert_eq!(game.players[1].controller, Controller::Remote);
+        assert_eq!(game.players[2].controller, Controller::Human);
+        assert_eq!(game.players[2].name, "Ada");
+        assert_eq!(game.players[3].name, "Player 4");
+        assert!(game.time_control().is_some());
+        assert_eq!(events.borrow()[0], GameEvent::Dealt);
+    }
+
+    #[test]
+    fn test_debug() {
+        let config = GameConfig::new()
+            .seed(7)
+            .time_control(
+                TimeControl::per_turn(Duration::from_secs(30)),
+                ManualClock::new(),
+            )
+            .observer(|_: &GameEvent| {});
+        let debug = alloc::format!("{:?}", config);
+        assert!(debug.contains("num_players: 4"));
+        assert!(debug.contains("seed: Some(7)"));
+        assert!(debug.contains("per_turn: 30s"));
+        assert!(debug.ends_with(", .. }"));
+    }
+
+    #[test]
+    fn test_rules() {
+        let deal = Prearranged::in_order().deal(Deck::new());
+        let mut game = GameConfig::new()
+            .deal(deal)
+            .rules(Rules {
+                three_of_clubs_opens: false,
+            })
+            .build()
+            .unwrap();
+        let leader = game.current_seat();
+        assert!(game.player(leader).cards.contains(&THREE_OF_CLUBS));
+        assert_eq!(game.log().entries().len(), 1);
+
+        // the Three of Clubs leads, but need not be played
+        let action: Action = "4C".parse().unwrap();
+        assert!(game.legal_actions(leader).contains(&action));
+        game.apply(leader, action).unwrap();
+        assert_eq!(Game::replay(game.log()).unwrap().state(), game.state());
+        let loaded = Game::load_json(&game.save_json().unwrap()).unwrap();
+        assert!(!loaded.rules().three_of_clubs_opens);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let deal = || Prearranged::in_order().deal(Deck::new());
+        let errors = [
+            GameConfig::new().players(3).build(),
+            GameConfig::new().seed(1).deal(deal()).build(),
+            GameConfig::new().seed(1).leader(seat(1)).build(),
+            GameConfig::new().deal(Deal::default()).build(),
+            GameConfig::new()
+                .seed(1)
+                .time_control(TimeControl::per_turn(Duration::ZERO), ManualClock::new())
+                .build(),
+        ];
+        assert!(matches!(
+            errors,
+            [
+                Err(ConfigError::UnsupportedPlayerCount(3)),
+                Err(ConfigError::SeedAndDeal),
+                Err(ConfigError::LeaderWithoutDeal),
+                Err(ConfigError::DealError(_)),
+                Err(ConfigError::NoTimePerTurn),
+            ]
+        ));
+        assert_eq!(
+            ConfigError::UnsupportedPlayerCount(3).to_string(),
+            "3 players are not supported, only 4"
+        );
+
+        // an uneven deal is fine with a leader
+        let mut hands = deal();
+        let card = hands[0].pop().unwrap();
+        hands[1].push(card);
+        assert!(GameConfig::new().deal(hands.clone()).build().is_err());
+        assert!(GameConfig::new()
+            .deal(hands)
+            .leader(seat(3))
+            .build()
+            .is_ok());
+    }
+}
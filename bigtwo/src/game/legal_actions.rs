@@ -0,0 +1,242 @@
+//! Enumerates every Action a Player holding some Cards may take in a given position.
+
+use alloc::vec::Vec;
+
+use crate::action::Action;
+use crate::card::rank::Rank;
+use crate::card::set::CardSet;
+use crate::card::suit::Suit;
+use crate::card::{Card, THREE_OF_CLUBS};
+use crate::game::rules::Rules;
+use crate::hand::kind::HandKind;
+use crate::hand::order::order;
+use crate::hand::Hand;
+
+/// Number of Ranks in a Straight.
+const STRAIGHT_LENGTH: usize = 5;
+
+/// Every Action a Player holding these Cards may take, i.e. every Action that
+/// check_player_can_play_hand would allow. Pass comes first (when allowed), then the Hands by
+/// HandKind, weakest first.
+pub fn legal_actions(
+    cards: &CardSet,
+    last_played_hand: Option<&Hand>,
+    is_start_trick: bool,
+    rules: &Rules,
+) -> Vec<Action> {
+    let mut hands = Vec::new();
+    match last_played_hand {
+        // must play three of clubs to start game, if the leader has it and the Rules say so
+        None => {
+            all_hands(cards, &mut hands);
+            if rules.three_of_clubs_opens && cards.contains(&THREE_OF_CLUBS) {
+                hands.retain(|hand| hand.cards().any(|card| *card == THREE_OF_CLUBS));
+            }
+        }
+        // can play anything to start a trick
+        Some(_) if is_start_trick => all_hands(cards, &mut hands),
+        // otherwise only what beats the last Hand, which means the same number of cards
+        Some(last) => {
+            match last.kind() {
+                HandKind::Lone => lones(cards, &mut hands),
+                HandKind::Pair => same_rank::<2>(cards, &mut hands),
+                HandKind::Trips => same_rank::<3>(cards, &mut hands),
+                _ => fivers(cards, &mut hands),
+            }
+            hands.retain(|hand| order(last, hand) == Some(core::cmp::Ordering::Less));
+        }
+    }
+    hands.sort_by_key(|hand| (hand.kind(), hand.strength()));
+
+    let mut actions = Vec::with_capacity(hands.len() + 1);
+    if !is_start_trick {
+        actions.push(Action::Pass);
+    }
+    actions.extend(hands.into_iter().map(Action::Play));
+    actions
+}
+
+/// Every Hand that can be made from these Cards.
+fn all_hands(cards: &CardSet, hands: &mut Vec<Hand>) {
+    lones(cards, hands);
+    same_rank::<2>(cards, hands);
+    same_rank::<3>(cards, hands);
+    fivers(cards, hands);
+}
+
+fn lones(cards: &CardSet, hands: &mut Vec<Hand>) {
+    hands.extend(cards.iter().map(Hand::Lone));
+}
+
+/// Every Pair (N = 2) or Trips (N = 3) that can be made from these Cards.
+fn same_rank<const N: usize>(cards: &CardSet, hands: &mut Vec<Hand>) {
+    for rank in Rank::all() {
+        for_each_combination::<N>(&cards.of_rank(rank).to_vec(), &mut |combo| {
+            hands.extend(Hand::try_from_cards(combo).ok());
+        });
+    }
+}
+
+/// Every five card Hand that can be made from these Cards, each generated exactly once.
+fn fivers(cards: &CardSet, hands: &mut Vec<Hand>) {
+    let by_rank: Vec<Vec<Card>> = Rank::all()
+        .iter()
+        .map(|rank| cards.of_rank(*rank).to_vec())
+        .collect();
+
+    // Straights and Straight Flushes: one Card from each of five consecutive Ranks
+    for run in by_rank.windows(STRAIGHT_LENGTH) {
+        let mut combo = [THREE_OF_CLUBS; STRAIGHT_LENGTH];
+        for_each_pick(run, &mut combo, 0, &mut |combo| {
+            hands.extend(Hand::try_from_cards(combo).ok());
+        });
+    }
+
+    // Flushes, except those that are Straight Flushes, which were made above
+    for suit in Suit::all() {
+        for_each_combination::<5>(&cards.of_suit(suit).to_vec(), &mut |combo| {
+            if let Ok(hand @ Hand::Flush(..)) = Hand::try_from_cards(combo) {
+                hands.push(hand);
+            }
+        });
+    }
+
+    // Full Houses: Trips of one Rank plus a Pair of another
+    for (trip_rank, trip_cards) in by_rank.iter().enumerate() {
+        for_each_combination::<3>(trip_cards, &mut |trip| {
+            for (pair_rank, pair_cards) in by_rank.iter().enumerate() {
+                if pair_rank == trip_rank {
+                    continue;
+                }
+                for_each_combination::<2>(pair_cards, &mut |pair| {
+                    hands.extend(Hand::try_fiver(trip[0], trip[1], trip[2], pair[0], pair[1]).ok());
+                });
+            }
+        });
+    }
+
+    // Four Plus Kicks: all four Cards of one Rank plus any other Card
+    for quad in by_rank.iter().filter(|quad| quad.len() == 4) {
+        for kick in cards.iter().filter(|card| card.rank != quad[0].rank) {
+            hands.extend(Hand::try_fiver(quad[0], quad[1], quad[2], quad[3], kick).ok());
+        }
+    }
+}
+
+/// Calls f with every combination of N of the given Cards, without allocating.
+fn for_each_combination<const N: usize>(cards: &[Card], f: &mut impl FnMut(&[Card])) {
+    fn recurse(cards: &[Card], combo: &mut [Card], filled: usize, f: &mut impl FnMut(&[Card])) {
+        if filled == combo.len() {
+            f(combo);
+            return;
+        }
+        // leave enough Cards to fill the rest of the combination
+        let needed = combo.len() - filled;
+        for i in 0..(cards.len() + 1).saturating_sub(needed) {
+            combo[filled] = cards[i];
+            recurse(&cards[i + 1..], combo, filled + 1, f);
+        }
+    }
+    let mut combo = [THREE_OF_CLUBS; N];
+    recurse(cards, &mut combo, 0, f);
+}
+
+/// Calls f with every way of picking one Card from each of the groups.
+fn for_each_pick(
+    groups: &[Vec<Card>],
+    combo: &mut [Card],
+    filled: usize,
+    f: &mut impl FnMut(&[Card]),
+) {
+    match groups.split_first() {
+        None => f(combo),
+        Some((group, rest)) => {
+            for card in group {
+                combo[filled] = *card;
+                for_each_pick(rest, combo, filled + 1, f);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::game::check_player_can_play_hand::check_player_can_play_hand;
+    use crate::tests::test_util::vec_card_from_str;
+    use std::collections::BTreeSet;
+
+    /// Tries every subset of one, two, three or five of the Cards, the slow but obvious way.
+    fn brute_force(cards: &CardSet, last: Option<&Hand>, is_start_trick: bool) -> BTreeSet<Hand> {
+        let cards_vec = cards.to_vec();
+        let mut legal = BTreeSet::new();
+        let mut check = |combo: &[Card]| {
+            if let Ok(hand) = Hand::try_from_cards(combo) {
+                let action = Action::Play(hand);
+                let rules = Rules::default();
+                if check_player_can_play_hand(last, cards, &action, is_start_trick, &rules).is_ok()
+                {
+                    legal.insert(hand);
+                }
+            }
+        };
+        for_each_combination::<1>(&cards_vec, &mut check);
+        for_each_combination::<2>(&cards_vec, &mut check);
+        for_each_combination::<3>(&cards_vec, &mut check);
+        for_each_combination::<5>(&cards_vec, &mut check);
+        legal
+    }
+
+    fn hands(actions: &[Action]) -> BTreeSet<Hand> {
+        actions.iter().filter_map(Action::hand).copied().collect()
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let cards: CardSet = vec_card_from_str("3C 4C 5C 5D 5H 5S 6C 6D 7C 7H 8S 9C 2D").into();
+        let positions: [(Option<&str>, bool); 8] = [
+            (None, true),
+            (Some("3D"), true),
+            (Some("6H"), false),
+            (Some("4S 4D"), false),
+            (Some("3S 3H 3D"), false),
+            (Some("8D 7S 6H 5S 4S"), false),
+            (Some("KS KD KC 4S 4D"), false),
+            (Some("TS TH TD TC 3D"), false),
+        ];
+        for (last, is_start_trick) in positions {
+            let last: Option<Hand> = last.map(|hand| hand.parse().unwrap());
+            let actions = legal_actions(&cards, last.as_ref(), is_start_trick, &Rules::default());
+            assert_eq!(
+                hands(&actions),
+                brute_force(&cards, last.as_ref(), is_start_trick),
+                "{last:?}"
+            );
+            // no duplicates, and Pass exactly when a trick is underway
+            assert_eq!(
+                actions.len(),
+                hands(&actions).len() + usize::from(!is_start_trick)
+            );
+            assert_eq!(actions.first() == Some(&Action::Pass), !is_start_trick);
+        }
+    }
+
+    #[test]
+    fn test_opening_and_passing() {
+        // a leader without the Three of Clubs may open with anything
+        let cards: CardSet = vec_card_from_str("4C 5C").into();
+        assert_eq!(
+            legal_actions(&cards, None, true, &Rules::default()).len(),
+            2
+        );
+
+        // with nothing that beats the last Hand, Pass is all that is left
+        let last: Hand = "2S".parse().unwrap();
+        let rules = Rules::default();
+        assert_eq!(
+            legal_actions(&cards, Some(&last), false, &rules),
+            [Action::Pass]
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! An append-only record of a Game: the deal, then every Action with the Seat that took it. An
+//! Action taken back is not removed, its Seat's undo is recorded after it. Replaying the log
+//! reconstructs the Game exactly, checking every step along the way.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::dealer::{Deal, DealError};
+use crate::game::rules::Rules;
+use crate::game::{Game, GameStepError};
+use crate::player::seat::Seat;
+
+/// One line of a GameLog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum LogEntry {
+    /// The Cards dealt to each Seat, the Seat to lead and the Rules, always the first entry.
+    Deal {
+        hands: Deal,
+        leader: Seat,
+        #[serde(default)]
+        rules: Rules,
+    },
+    /// The Seat took the Action.
+    Action { seat: Seat, action: Action },
+    /// The Seat forfeited.
+    Forfeit { seat: Seat },
+    /// The last Action, which the Seat took, was taken back, see Game::undo.
+    Undo { seat: Seat },
+}
+
+/// Everything needed to reconstruct a Game, in the order it happened.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameLog {
+    entries: Vec<LogEntry>,
+}
+
+/// Represents the ways a GameLog can fail to replay.
+#[derive(Debug)]
+pub enum ReplayError {
+    /// The log does not start with the deal.
+    MissingDeal,
+    /// The deal appears again after the start of the log, at this entry.
+    UnexpectedDeal(usize),
+    /// The logged hands are not exactly one full Deck.
+    DealError(DealError),
+    /// The Action at this entry could not be applied.
+    StepError(usize, GameStepError),
+    /// The undo at this entry does not take back an Action its Seat took last.
+    BadUndo(usize),
+    /// A line of JSON could not be read, at this line number (starting from 1).
+    Json(usize, serde_json::Error),
+}
+
+impl From<DealError> for ReplayError {
+    fn from(e: DealError) -> Self {
+        Self::DealError(e)
+    }
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::MissingDeal => write!(f, "the log does not start with the deal"),
+            Self::UnexpectedDeal(i) => write!(f, "entry {} deals again", i),
+            Self::DealError(e) => write!(f, "DealError! {}", e),
+            Self::StepError(i, e) => write!(f, "entry {}: {}", i, e),
+            Self::BadUndo(i) => write!(f, "entry {} undoes an action it cannot", i),
+            Self::Json(line, e) => write!(f, "line {}: {}", line, e),
+        }
+    }
+}
+
+impl GameLog {
+    /// A log starting with this deal, of a Game played by these Rules.
+    pub fn new(hands: Deal, leader: Seat, rules: Rules) -> Self {
+        GameLog {
+            entries: alloc::vec![LogEntry::Deal {
+                hands,
+                leader,
+                rules
+            }],
+        }
+    }
+
+    /// Every entry, oldest first.
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+
+    /// Appends an entry, a log is never changed in any other way.
+    pub fn push(&mut self, entry: LogEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Every Action taken and not taken back, oldest first, with the Seat that took it.
+    pub fn actions(&self) -> impl Iterator<Item = (Seat, Action)> + '_ {
+        let mut actions = Vec::new();
+        for entry in &self.entries {
+            match entry {
+                LogEntry::Action { seat, action } => actions.push((*seat, *action)),
+                LogEntry::Undo { .. } => {
+                    actions.pop();
+                }
+                LogEntry::Deal { .. } | LogEntry::Forfeit { .. } => {}
+            }
+        }
+        actions.into_iter()
+    }
+
+    /// The log as it was after this many entries past the deal, i.e. Actions, forfeits and
+    /// undos, for replaying an intermediate state.
+    pub fn prefix(&self, actions: usize) -> GameLog {
+        let deals = self
+            .entries
+            .iter()
+            .take_while(|entry| matches!(entry, LogEntry::Deal { .. }))
+            .count();
+        GameLog {
+            entries: self.entries.iter().take(deals + actions).cloned().collect(),
+        }
+    }
+
+    /// One JSON object per entry, one entry per line.
+    pub fn to_json_lines(&self) -> Result<String, serde_json::Error> {
+        let mut out = String::new();
+        for entry in &self.entries {
+            out.push_str(&serde_json::to_string(entry)?);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Reads a log written by to_json_lines, blank lines are ignored.
+    pub fn from_json_lines(json_lines: &str) -> Result<GameLog, ReplayError> {
+        let mut entries = Vec::new();
+        for (i, line) in json_lines.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            entries.push(serde_json::from_str(line).map_err(|e| ReplayError::Json(i + 1, e))?);
+        }
+        Ok(GameLog { entries })
+    }
+}
+
+impl Game {
+    /// The log of this Game so far.
+    pub fn log(&self) -> &GameLog {
+        &self.log
+    }
+
+    /// Reconstructs a Game from its log, checking the deal and every Action on the way.
+    /// Replay a GameLog::prefix to reconstruct an intermediate state.
+    pub fn replay(log: &GameLog) -> Result<Game, ReplayError> {
+        let (first, rest) = log.entries.split_first().ok_or(ReplayError::MissingDeal)?;
+        let LogEntry::Deal {
+            hands,
+            leader,
+            rules,
+        } = first
+        else {
+            return Err(ReplayError::MissingDeal);
+        };
+        let mut game = Game::from_hands_with_rules(hands.clone(), *leader, *rules)?;
+        for (i, entry) in rest.iter().enumerate() {
+            game.replay_entry(i + 1, entry)?;
+        }
+        Ok(game)
+    }
+
+    /// Takes the move of the entry at this index of a log, as Game::replay does.
+    pub(crate) fn replay_entry(&mut self, i: usize, entry: &LogEntry) -> Result<(), ReplayError> {
+        match entry {
+            LogEntry::Deal { .. } => return Err(ReplayError::UnexpectedDeal(i)),
+            LogEntry::Action { seat, action } => {
+                self.apply(*seat, *action)
+                    .map_err(|e| ReplayError::StepError(i, e))?;
+            }
+            LogEntry::Forfeit { seat } => {
+                self.forfeit(*seat)
+                    .map_err(|e| ReplayError::StepError(i, e))?;
+            }
+            LogEntry::Undo { seat } => match self.undo() {
+                Some((undone, _)) if undone == *seat => {}
+                _ => return Err(ReplayError::BadUndo(i)),
+            },
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tests::test_util::played_game;
+
+    #[test]
+    fn test_replay() {
+        let game = played_game(7, 12);
+        assert_eq!(game.log().actions().count(), 12);
+
+        let replayed = Game::replay(game.log()).unwrap();
+        assert_eq!(replayed.state(), game.state());
+        assert_eq!(replayed.played_hands, game.played_hands);
+        assert_eq!(replayed.log(), game.log());
+
+        // intermediate states too
+        let start = Game::replay(&game.log().prefix(0)).unwrap();
+        assert!(start.played_hands.is_empty());
+        let halfway = Game::replay(&game.log().prefix(6)).unwrap();
+        assert_eq!(halfway.log().actions().count(), 6);
+    }
+
+    #[test]
+    fn test_replay_undo() {
+        let mut game = played_game(7, 12);
+        game.undo().unwrap();
+        let (seat, _) = game.undo().unwrap();
+        assert_eq!(game.log().entries().len(), 15);
+        assert_eq!(game.log().entries().last(), Some(&LogEntry::Undo { seat }));
+        assert_eq!(game.log().actions().count(), 10);
+        let action = *game.legal_actions(game.current_seat()).last().unwrap();
+        game.apply(game.current_seat(), action).unwrap();
+
+        let replayed = Game::replay(game.log()).unwrap();
+        assert_eq!(replayed.state(), game.state());
+        assert_eq!(replayed.played_hands, game.played_hands);
+        assert_eq!(replayed.log(), game.log());
+
+        // an undo with nothing to undo, and an undo by a Seat that did not take the last Action
+        let mut log = game.log().prefix(0);
+        log.push(LogEntry::Undo { seat });
+        assert!(matches!(Game::replay(&log), Err(ReplayError::BadUndo(1))));
+        let first = game.log().actions().next().unwrap().0;
+        let mut log = game.log().prefix(1);
+        log.push(LogEntry::Undo { seat: first.next() });
+        assert!(matches!(Game::replay(&log), Err(ReplayError::BadUndo(2))));
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let game = played_game(7, 12);
+        let json_lines = game.log().to_json_lines().unwrap();
+        assert_eq!(json_lines.lines().count(), 13);
+        assert!(json_lines.starts_with(r#"{"event":"deal","hands":[["#));
+
+        let log = GameLog::from_json_lines(&json_lines).unwrap();
+        assert_eq!(&log, game.log());
+        assert_eq!(Game::replay(&log).unwrap().state(), game.state());
+    }
+
+    #[test]
+    fn test_replay_is_validated() {
+        let game = played_game(7, 12);
+        assert!(matches!(
+            Game::replay(&GameLog::default()),
+            Err(ReplayError::MissingDeal)
+        ));
+
+        // an Action by the wrong Seat
+        let mut log = game.log().prefix(0);
+        let seat = game.log().actions().next().unwrap().0;
+        log.push(LogEntry::Action {
+            seat: seat.next(),
+            action: Action::Pass,
+        });
+        assert!(matches!(
+            Game::replay(&log),
+            Err(ReplayError::StepError(1, GameStepError::NotYourTurn(_)))
+        ));
+
+        // a deal that is not a full Deck
+        let mut hands = Deal::default();
+        hands[0] = alloc::vec!["3C".parse().unwrap()];
+        assert!(matches!(
+            Game::replay(&GameLog::new(hands, Seat::default(), Rules::default())),
+            Err(ReplayError::DealError(_))
+        ));
+    }
+}
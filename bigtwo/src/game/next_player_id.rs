@@ -1,60 +1,66 @@
 //! Identifies the Player who is up next.
-use std::collections::BTreeSet;
-
-/// Identifies the Player who is up next.
-/// # Panics:
-/// - Panics if current_player_id < num_players, since the id is actually an idx into [Player; 4].
-/// - Panics if there are 0 players who have not passed, that is not a valid game state (Trick ends when only 1 remaining player has not passed).
-/// - Panics if the computed next_id is equal to the current_player_id
-pub fn next_player_id(
-    current_player_id: usize,
-    passed_player_ids: &BTreeSet<usize>,
-    num_players: usize,
-) -> usize {
-    assert!(
-        current_player_id < num_players,
-        "{current_player_id} is an index into an array of Players so it must be < num_players"
-    );
-    assert!(
-        passed_player_ids.len() < num_players,
-        "all players cannot pass, one player must have not passed"
-    );
-    for i in 1..num_players {
-        let next_id = (current_player_id + i) % num_players;
-        if !passed_player_ids.contains(&next_id) {
-            assert_ne!(current_player_id, next_id);
-            return next_id;
+use core::fmt::Display;
+
+use crate::player::seat::{Seat, SeatSet};
+
+/// Represents the game states from which there is no next Player.
+#[derive(Debug)]
+pub enum NextPlayerError {
+    /// Trick ends when only 1 remaining player has not passed, so they cannot all have passed.
+    EveryonePassed,
+}
+
+impl Display for NextPlayerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::EveryonePassed => write!(f, "every other player has passed"),
+        }
+    }
+}
+
+/// Identifies the Player who is up next, going around the table from the current Seat.
+/// Returns an Error if every Player besides the current one has passed (that is not a valid game
+/// state).
+pub fn next_player_id(current_seat: Seat, passed_seats: &SeatSet) -> Result<Seat, NextPlayerError> {
+    let mut next_seat = current_seat.next();
+    while next_seat != current_seat {
+        if !passed_seats.contains(&next_seat) {
+            return Ok(next_seat);
         }
+        next_seat = next_seat.next();
     }
-    unreachable!();
+    Err(NextPlayerError::EveryonePassed)
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use crate::game::NUM_PLAYERS;
+    use crate::tests::test_util::seat;
 
     #[test]
     fn test_next_player_id() {
-        let current: usize = 0;
-        let has_passed: BTreeSet<usize> = BTreeSet::new();
-        let next = next_player_id(current, &has_passed, NUM_PLAYERS);
-        assert_eq!(next, 1);
-
-        let current: usize = 3;
-        let has_passed: BTreeSet<usize> = BTreeSet::new();
-        let next = next_player_id(current, &has_passed, NUM_PLAYERS);
-        assert_eq!(next, 0);
-
-        let current: usize = 0;
-        let has_passed: BTreeSet<usize> = BTreeSet::from([1, 2]);
-        let next = next_player_id(current, &has_passed, NUM_PLAYERS);
-        assert_eq!(next, 3);
-
-        let current: usize = 2;
-        let has_passed: BTreeSet<usize> = BTreeSet::from([0, 3]);
-        let next = next_player_id(current, &has_passed, NUM_PLAYERS);
-        assert_eq!(next, 1);
+        let has_passed = SeatSet::new();
+        let next = next_player_id(seat(0), &has_passed).unwrap();
+        assert_eq!(next, seat(1));
+
+        let has_passed = SeatSet::new();
+        let next = next_player_id(seat(3), &has_passed).unwrap();
+        assert_eq!(next, seat(0));
+
+        let has_passed = SeatSet::from_iter([seat(1), seat(2)]);
+        let next = next_player_id(seat(0), &has_passed).unwrap();
+        assert_eq!(next, seat(3));
+
+        let has_passed = SeatSet::from_iter([seat(0), seat(3)]);
+        let next = next_player_id(seat(2), &has_passed).unwrap();
+        assert_eq!(next, seat(1));
+    }
+
+    #[test]
+    fn test_no_next_player_id() {
+        let has_passed = SeatSet::from_iter([seat(0), seat(1), seat(3)]);
+        let next = next_player_id(seat(2), &has_passed);
+        assert!(matches!(next, Err(NextPlayerError::EveryonePassed)));
     }
 }
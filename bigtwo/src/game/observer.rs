@@ -0,0 +1,97 @@
+//! Lets anything (a CLI, a server, a log, a test) follow a Game as it is played, instead of the
+//! library printing to stdout itself.
+
+use alloc::vec::Vec;
+
+use crate::action::Action;
+use crate::game::outcome::{Standing, StepOutcome};
+use crate::hand::Hand;
+use crate::player::seat::Seat;
+use crate::player::status::SeatStatus;
+
+/// Something that happened in a Game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    /// The Cards have been dealt and the Game is about to begin.
+    Dealt,
+    /// It is now this Seat's turn.
+    TurnStarted(Seat),
+    /// This Seat played this Hand.
+    Played(Seat, Hand),
+    /// This Seat passed.
+    Passed(Seat),
+    /// Everybody else passed, so this Seat won the Trick and leads the next one.
+    TrickWon(Seat),
+    /// This Seat played its last Card.
+    PlayerOut(Seat),
+    /// The Game is over, these are the final standings, best first.
+    GameOver(Vec<Standing>),
+    /// This Seat's Action was taken back, it is that Seat's turn again.
+    Undone(Seat, Action),
+    /// This Seat ran out of time, what happens next depends on the TimeoutPolicy.
+    TimedOut(Seat),
+    /// This Seat forfeited, the Game carries on without them.
+    Forfeited(Seat),
+    /// The Player at this Seat disconnected, reconnected, or a bot took over or handed back.
+    SeatStatusChanged(Seat, SeatStatus),
+}
+
+impl GameEvent {
+    /// The events describing one step, in the order they happened.
+    pub fn from_outcome(outcome: &StepOutcome) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        events.push(match outcome.action.hand() {
+            Some(hand) => GameEvent::Played(outcome.seat, *hand),
+            None => GameEvent::Passed(outcome.seat),
+        });
+        if let Some(winner) = outcome.trick_winner {
+            events.push(GameEvent::TrickWon(winner));
+        }
+        if let Some(out) = outcome.went_out {
+            events.push(GameEvent::PlayerOut(out));
+        }
+        if let Some(standings) = &outcome.standings {
+            events.push(GameEvent::GameOver(standings.clone()));
+        }
+        events
+    }
+}
+
+/// Registered on a Game with Game::add_observer, and told about every GameEvent from then on.
+pub trait GameObserver {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+/// Any closure taking a GameEvent is a GameObserver.
+impl<F: FnMut(&GameEvent)> GameObserver for F {
+    fn on_event(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_from_outcome() {
+        let seat = Seat::new(1).unwrap();
+        let hand: Hand = "3C".parse().unwrap();
+        let outcome = StepOutcome {
+            seat,
+            action: Action::Play(hand),
+            trick_winner: None,
+            went_out: Some(seat),
+            standings: Some(vec![]),
+        };
+        assert_eq!(
+            GameEvent::from_outcome(&outcome),
+            [
+                GameEvent::Played(seat, hand),
+                GameEvent::PlayerOut(seat),
+                GameEvent::GameOver(vec![]),
+            ]
+        );
+    }
+}
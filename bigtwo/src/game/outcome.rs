@@ -0,0 +1,146 @@
+//! Describes what happened in one step of a Game, so callers do not have to re-derive it.
+
+use alloc::vec::Vec;
+
+use crate::action::Action;
+use crate::game::state::GameState;
+use crate::player::seat::Seat;
+
+/// Where a Seat finished, see GameState::standings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Standing {
+    pub seat: Seat,
+    /// Number of Cards the Seat was still holding when the Game ended, 0 for the winner.
+    pub cards_left: usize,
+}
+
+/// Everything that happened as a result of one Action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepOutcome {
+    /// The Seat that took the Action.
+    pub seat: Seat,
+
+    /// What that Seat played, or that it passed.
+    pub action: Action,
+
+    /// The Seat that won the Trick, if this Action ended it. The winner leads the next Trick.
+    pub trick_winner: Option<Seat>,
+
+    /// The Seat that played its last Card with this Action, if any.
+    pub went_out: Option<Seat>,
+
+    /// The final standings, best first, if this Action ended the Game.
+    pub standings: Option<Vec<Standing>>,
+}
+
+impl StepOutcome {
+    /// Works out what happened when `action` took the Game from `before` to `after`.
+    pub fn new(before: &GameState, action: Action, after: &GameState) -> Self {
+        let seat = before.current_seat();
+        // the winner leads the next Trick, even if the last Hand was played by a Seat that has
+        // since forfeited
+        let trick_winner = if after.is_start_trick() && after.last_play().is_some() {
+            Some(after.current_seat())
+        } else {
+            None
+        };
+        let went_out = match action {
+            Action::Play(_) if after.hand(seat).is_empty() => Some(seat),
+            _ => None,
+        };
+        let standings = after.is_over().then(|| after.standings());
+        StepOutcome {
+            seat,
+            action,
+            trick_winner,
+            went_out,
+            standings,
+        }
+    }
+
+    /// True if this Action ended the Game.
+    pub fn is_game_over(&self) -> bool {
+        self.standings.is_some()
+    }
+}
+
+impl GameState {
+    /// Every Seat ranked by how few Cards it holds, ties go to the earlier Seat. Seats that
+    /// forfeited come last, however few Cards they hold.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = Seat::all()
+            .into_iter()
+            .map(|seat| Standing {
+                seat,
+                cards_left: self.hand(seat).len(),
+            })
+            .collect();
+        // sort is stable, so ties stay in Seat order
+        let forfeited = self.forfeited();
+        standings.sort_by_key(|standing| (forfeited.contains(&standing.seat), standing.cards_left));
+        standings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tests::test_util::{seat, vec_card_from_str};
+
+    #[test]
+    fn test_step_outcome() {
+        let start = GameState::new([
+            vec_card_from_str("4C 5C").into(),
+            vec_card_from_str("3C 6C").into(),
+            vec_card_from_str("4D").into(),
+            vec_card_from_str("4H 8C").into(),
+        ])
+        .unwrap();
+
+        // an ordinary play
+        let action: Action = "3C".parse().unwrap();
+        let after = start.apply(&action).unwrap();
+        let outcome = StepOutcome::new(&start, action, &after);
+        assert_eq!(outcome.seat, seat(1));
+        assert_eq!(outcome.action, action);
+        assert_eq!(outcome.trick_winner, None);
+        assert_eq!(outcome.went_out, None);
+        assert!(!outcome.is_game_over());
+
+        // passing until the trick ends
+        let mut state = after;
+        let mut outcome = None;
+        for _ in 0..3 {
+            let next = state.apply(&Action::Pass).unwrap();
+            outcome = Some(StepOutcome::new(&state, Action::Pass, &next));
+            state = next;
+        }
+        let outcome = outcome.unwrap();
+        assert_eq!(outcome.seat, seat(0));
+        assert_eq!(outcome.trick_winner, Some(seat(1)));
+        assert!(!outcome.is_game_over());
+    }
+
+    #[test]
+    fn test_standings() {
+        let state = GameState::new([
+            vec_card_from_str("4C 5C").into(),
+            vec_card_from_str("3C").into(),
+            vec_card_from_str("4D 7C 9D").into(),
+            vec_card_from_str("4H 8C").into(),
+        ])
+        .unwrap();
+        let action: Action = "3C".parse().unwrap();
+        let after = state.apply(&action).unwrap();
+        let outcome = StepOutcome::new(&state, action, &after);
+        assert_eq!(outcome.went_out, Some(seat(1)));
+        let standings: Vec<(usize, usize)> = outcome
+            .standings
+            .unwrap()
+            .iter()
+            .map(|standing| (standing.seat.index(), standing.cards_left))
+            .collect();
+        assert_eq!(standings, [(1, 0), (0, 2), (3, 2), (2, 3)]);
+    }
+}
@@ -0,0 +1,504 @@
+//! A one-line notation for a position, like FEN is for chess, e.g. to share puzzles, to set up
+//! tests, or to start an analysis from. The fields are separated by spaces:
+//!
+//! ```text
+//! 3C4C5H/13/KD2D/- 1:KSKH 2 - 3 standard
+//! ```
+//!
+//! 1. The Cards at each Seat, in Seat order, separated by "/": the Cards written one after
+//!    another, or only how many there are for a public position, or "-" for none at all.
+//! 2. The Hand to beat and the Seat that played it, or "-" before the first Hand of the Game.
+//! 3. The Seats that have passed this Trick, or "-" for none.
+//! 4. The Seats that have forfeited, or "-" for none.
+//! 5. The Seat to move, followed by "*" when it may lead anything.
+//! 6. The Rules, by name.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::card::set::CardSet;
+use crate::card::{Card, ParseCardError};
+use crate::dealer::NUM_CARDS_PER_PLAYER;
+use crate::game::rules::{ParseRulesError, Rules};
+use crate::game::state::GameState;
+use crate::game::view::PlayerView;
+use crate::game::NUM_PLAYERS;
+use crate::hand::try_from::ParseHandError;
+use crate::hand::Hand;
+use crate::player::seat::{Seat, SeatSet};
+
+/// Represents the ways a string is not a Position, or a Position is not a GameState.
+#[derive(Debug)]
+pub enum PositionError {
+    /// There must be exactly 6 fields, not this many.
+    WrongFieldCount(usize),
+    /// There must be exactly NUM_PLAYERS Seats in the first field, not this many.
+    WrongSeatCount(usize),
+    /// Not able to parse one of the Cards held at a Seat.
+    BadCard(ParseCardError),
+    /// Not able to parse the Hand to beat.
+    BadHand(ParseHandError),
+    /// Not a Seat at the table.
+    BadSeat(String),
+    /// Not one of the named Rules.
+    BadRules(ParseRulesError),
+    /// This Card is held twice, or held and also in the Hand to beat.
+    DuplicateCard(Card),
+    /// The Seat to move has passed or forfeited, so it cannot be its turn.
+    CannotMove(Seat),
+    /// Only the number of Cards held at this Seat is known.
+    HiddenCards(Seat),
+    /// This Seat holds more Cards than anybody is dealt.
+    TooManyCards(Seat),
+    /// There is no Hand to beat, yet the Seat to move may not lead anything.
+    NothingToBeat,
+    /// This Seat is written more than once in the passed or forfeited Seats.
+    RepeatedSeat(Seat),
+    /// This Seat has passed, yet a new Trick is starting, which clears the passes.
+    PassedAtStartTrick(Seat),
+}
+
+impl From<ParseCardError> for PositionError {
+    fn from(e: ParseCardError) -> Self {
+        Self::BadCard(e)
+    }
+}
+
+impl From<ParseHandError> for PositionError {
+    fn from(e: ParseHandError) -> Self {
+        Self::BadHand(e)
+    }
+}
+
+impl From<ParseRulesError> for PositionError {
+    fn from(e: ParseRulesError) -> Self {
+        Self::BadRules(e)
+    }
+}
+
+impl Display for PositionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::WrongFieldCount(n) => write!(f, "expected 6 fields, found {}", n),
+            Self::WrongSeatCount(n) => {
+                write!(f, "expected {} seats, found {}", NUM_PLAYERS, n)
+            }
+            Self::BadCard(e) => write!(f, "ParseCardError! {:?}", e),
+            Self::BadHand(e) => write!(f, "ParseHandError! {}", e),
+            Self::BadSeat(s) => write!(f, "\"{}\" is not a seat", s),
+            Self::BadRules(e) => write!(f, "{}", e),
+            Self::DuplicateCard(card) => write!(f, "{} appears more than once", card),
+            Self::CannotMove(seat) => {
+                write!(f, "seat {} cannot move, it has passed or forfeited", seat)
+            }
+            Self::HiddenCards(seat) => write!(f, "the cards of seat {} are hidden", seat),
+            Self::TooManyCards(seat) => write!(
+                f,
+                "seat {} holds more than {} cards",
+                seat, NUM_CARDS_PER_PLAYER
+            ),
+            Self::NothingToBeat => write!(f, "there is no hand to beat, so the seat must lead"),
+            Self::RepeatedSeat(seat) => write!(f, "seat {} is written more than once", seat),
+            Self::PassedAtStartTrick(seat) => {
+                write!(f, "seat {} passed, but a new trick is starting", seat)
+            }
+        }
+    }
+}
+
+/// What is known about the Cards held at one Seat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatCards {
+    /// Exactly these Cards.
+    Known(CardSet),
+    /// Only how many Cards there are.
+    Count(usize),
+}
+
+impl SeatCards {
+    /// How many Cards are held.
+    pub fn len(&self) -> usize {
+        match self {
+            SeatCards::Known(cards) => cards.len(),
+            SeatCards::Count(n) => *n,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Everything the rules need to decide what may happen next, as written in the notation. Unlike
+/// a GameState, some Seats' Cards may be hidden, see Position::public.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// What is known about the Cards held at each Seat, at the index of that Seat.
+    pub hands: [SeatCards; NUM_PLAYERS],
+
+    /// The Hand to beat and the Seat that played it, None until the first Hand of the Game.
+    pub last_play: Option<(Seat, Hand)>,
+
+    /// The Seats that have passed so far this Trick.
+    pub passed: SeatSet,
+
+    /// The Seats that have forfeited the Game.
+    pub forfeited: SeatSet,
+
+    /// The Seat whose turn it is.
+    pub to_move: Seat,
+
+    /// True when the Seat to move may lead anything.
+    pub is_start_trick: bool,
+
+    /// The house Rules the Game is played by.
+    pub rules: Rules,
+}
+
+impl Position {
+    /// The position as seen by everyone at the table: how many Cards each Seat holds, not which.
+    pub fn public(state: &GameState) -> Self {
+        let mut position = Position::from(state);
+        position.hands = state.hands().map(|hand| SeatCards::Count(hand.len()));
+        position
+    }
+
+    /// The position as seen from the Seat of this PlayerView, only its own Cards are known.
+    pub fn from_view(view: &PlayerView) -> Self {
+        let mut hands = view.card_counts.map(SeatCards::Count);
+        hands[view.seat.index()] = SeatCards::Known(view.cards);
+        Position {
+            hands,
+            last_play: view.last_play,
+            passed: view.passed,
+            forfeited: view.forfeited,
+            to_move: view.current_seat,
+            is_start_trick: view.is_start_trick,
+            rules: view.rules,
+        }
+    }
+
+    /// The Cards held at this Seat, None if they are hidden.
+    pub fn cards(&self, seat: Seat) -> Option<&CardSet> {
+        match &self.hands[seat.index()] {
+            SeatCards::Known(cards) => Some(cards),
+            SeatCards::Count(_) => None,
+        }
+    }
+
+    /// The Hand to beat, None until the first Hand of the Game.
+    pub fn last_hand(&self) -> Option<&Hand> {
+        self.last_play.as_ref().map(|(_, hand)| hand)
+    }
+
+    /// The GameState to play on from, or an Error naming a Seat whose Cards are hidden.
+    pub fn to_state(&self) -> Result<GameState, PositionError> {
+        let mut hands = [CardSet::new(); NUM_PLAYERS];
+        for seat in Seat::all() {
+            hands[seat.index()] = *self.cards(seat).ok_or(PositionError::HiddenCards(seat))?;
+        }
+        Ok(GameState::from_parts(
+            hands,
+            self.to_move,
+            self.last_play,
+            self.passed,
+            self.forfeited,
+            self.is_start_trick,
+            self.rules,
+        ))
+    }
+}
+
+impl From<&GameState> for Position {
+    /// The position with every Seat's Cards known.
+    fn from(state: &GameState) -> Self {
+        Position {
+            hands: state.hands().map(SeatCards::Known),
+            last_play: state.last_play(),
+            passed: state.passed(),
+            forfeited: state.forfeited(),
+            to_move: state.current_seat(),
+            is_start_trick: state.is_start_trick(),
+            rules: *state.rules(),
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for (i, hand) in self.hands.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            match hand {
+                SeatCards::Known(cards) if cards.is_empty() => write!(f, "-")?,
+                SeatCards::Known(cards) => write_cards(f, cards.iter())?,
+                SeatCards::Count(n) => write!(f, "{}", n)?,
+            }
+        }
+        match &self.last_play {
+            Some((seat, hand)) => {
+                write!(f, " {}:", seat)?;
+                write_cards(f, hand.cards().copied())?;
+            }
+            None => write!(f, " -")?,
+        }
+        write!(f, " ")?;
+        write_seats(f, &self.passed)?;
+        write!(f, " ")?;
+        write_seats(f, &self.forfeited)?;
+        write!(f, " {}", self.to_move)?;
+        if self.is_start_trick {
+            write!(f, "*")?;
+        }
+        write!(f, " {}", self.rules)
+    }
+}
+
+pub(crate) fn write_cards(
+    f: &mut core::fmt::Formatter,
+    cards: impl Iterator<Item = Card>,
+) -> core::fmt::Result {
+    for card in cards {
+        write!(f, "{}", card)?;
+    }
+    Ok(())
+}
+
+fn write_seats(f: &mut core::fmt::Formatter, seats: &SeatSet) -> core::fmt::Result {
+    if seats.is_empty() {
+        return write!(f, "-");
+    }
+    for seat in seats.iter() {
+        write!(f, "{}", seat)?;
+    }
+    Ok(())
+}
+
+impl FromStr for Position {
+    type Err = PositionError;
+
+    /// Reads a position in the notation described at the top of this module. The Seats' Cards
+    /// may be in any order. Positions no Game can reach are refused, see PositionError.
+    fn from_str(notation: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = notation.split_whitespace().collect();
+        let [hands, last_play, passed, forfeited, to_move, rules] = fields[..] else {
+            return Err(PositionError::WrongFieldCount(fields.len()));
+        };
+
+        let seats: Vec<&str> = hands.split('/').collect();
+        if seats.len() != NUM_PLAYERS {
+            return Err(PositionError::WrongSeatCount(seats.len()));
+        }
+        let mut hands = [SeatCards::Count(0); NUM_PLAYERS];
+        for ((hand, seat), cards) in hands.iter_mut().zip(Seat::all()).zip(seats) {
+            *hand = parse_seat_cards(cards)?;
+            // a count is hidden Cards dealt fairly, uneven deals have to be written out
+            if matches!(hand, SeatCards::Count(n) if *n > NUM_CARDS_PER_PLAYER) {
+                return Err(PositionError::TooManyCards(seat));
+            }
+        }
+
+        let last_play = match last_play {
+            "-" => None,
+            _ => {
+                let (seat, cards) = last_play
+                    .split_once(':')
+                    .ok_or_else(|| PositionError::BadSeat(last_play.into()))?;
+                let cards = parse_cards(cards)?;
+                Some((parse_seat(seat)?, Hand::try_from_cards(&cards)?))
+            }
+        };
+
+        // every Card is in one place only
+        let mut seen = CardSet::new();
+        let known = hands.iter().filter_map(|hand| match hand {
+            SeatCards::Known(cards) => Some(*cards),
+            SeatCards::Count(_) => None,
+        });
+        let played = last_play.iter().map(|(_, hand)| CardSet::from_hand(hand));
+        for card in known.chain(played).flat_map(|cards| cards.iter()) {
+            if !seen.insert(card) {
+                return Err(PositionError::DuplicateCard(card));
+            }
+        }
+
+        let (to_move, is_start_trick) = match to_move.strip_suffix('*') {
+            Some(seat) => (parse_seat(seat)?, true),
+            None => (parse_seat(to_move)?, false),
+        };
+        let passed = parse_seats(passed)?;
+        let forfeited = parse_seats(forfeited)?;
+        if passed.contains(&to_move) || forfeited.contains(&to_move) {
+            return Err(PositionError::CannotMove(to_move));
+        }
+        if last_play.is_none() && !is_start_trick {
+            return Err(PositionError::NothingToBeat);
+        }
+        if let Some(seat) = passed.iter().next().filter(|_| is_start_trick) {
+            return Err(PositionError::PassedAtStartTrick(seat));
+        }
+
+        Ok(Position {
+            hands,
+            last_play,
+            passed,
+            forfeited,
+            to_move,
+            is_start_trick,
+            rules: rules.parse()?,
+        })
+    }
+}
+
+/// "-", a number of Cards, or the Cards themselves.
+pub(crate) fn parse_seat_cards(s: &str) -> Result<SeatCards, PositionError> {
+    if s == "-" {
+        Ok(SeatCards::Known(CardSet::new()))
+    } else if let Ok(n) = s.parse() {
+        Ok(SeatCards::Count(n))
+    } else {
+        let mut set = CardSet::new();
+        for card in parse_cards(s)? {
+            if !set.insert(card) {
+                return Err(PositionError::DuplicateCard(card));
+            }
+        }
+        Ok(SeatCards::Known(set))
+    }
+}
+
+/// Cards written one after another, two characters each.
+pub(crate) fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    if s.is_empty() {
+        return Err(ParseCardError::Empty);
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| s.get(i..i + 2).ok_or(ParseCardError::BadLength)?.parse())
+        .collect()
+}
+
+pub(crate) fn parse_seat(s: &str) -> Result<Seat, PositionError> {
+    s.parse::<usize>()
+        .ok()
+        .and_then(|index| Seat::new(index).ok())
+        .ok_or_else(|| PositionError::BadSeat(s.into()))
+}
+
+/// "-", or the Seats one digit each, each only once.
+fn parse_seats(s: &str) -> Result<SeatSet, PositionError> {
+    let mut seats = SeatSet::new();
+    if s == "-" {
+        return Ok(seats);
+    }
+    for c in s.chars() {
+        let seat = parse_seat(c.encode_utf8(&mut [0; 4]))?;
+        if !seats.insert(seat) {
+            return Err(PositionError::RepeatedSeat(seat));
+        }
+    }
+    Ok(seats)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::action::Action;
+    use crate::tests::test_util::{in_order_game, seat};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_round_trip() {
+        let game = in_order_game(&["3C", "4D", ""]);
+        let position = Position::from(game.state());
+        let notation = position.to_string();
+        assert_eq!(
+            notation,
+            "4C5C6C7C8C9CTCJCQCKCAC2C/3D5D6D7D8D9DTDJDQDKDAD2D/3H4H5H6H7H8H9HTHJHQHKHAH2H/\
+             3S4S5S6S7S8S9STSJSQSKSAS2S 1:4D 2 - 3 standard"
+        );
+        assert_eq!(notation.parse::<Position>().unwrap(), position);
+        assert_eq!(position.to_state().unwrap(), *game.state());
+    }
+
+    #[test]
+    fn test_puzzle() {
+        // Seat 0 must beat a pair of Kings with only one pair left
+        let position: Position = "3C4C2H2S/2/3D/5 1:KSKH 2 - 0 free-opening".parse().unwrap();
+        assert_eq!(position.cards(seat(0)).unwrap().len(), 4);
+        assert_eq!(position.hands[1], SeatCards::Count(2));
+        assert_eq!(position.last_play.unwrap().0, seat(1));
+        assert!(!position.rules.three_of_clubs_opens);
+        assert!(matches!(
+            position.to_state(),
+            Err(PositionError::HiddenCards(s)) if s == seat(1)
+        ));
+
+        let position: Position = "3C4C2H2S/KD/3D/5S 1:KSKH 2 - 0 standard".parse().unwrap();
+        let state = position.to_state().unwrap();
+        let pair: Action = "2S 2H".parse().unwrap();
+        let after = state.apply(&pair).unwrap();
+        assert_eq!(
+            Position::from(&after).to_string(),
+            "3C4C/KD/3D/5S 0:2S2H 2 - 1 standard"
+        );
+    }
+
+    #[test]
+    fn test_public() {
+        let game = in_order_game(&[]);
+        let public = Position::public(game.state());
+        assert_eq!(public.to_string(), "13/13/13/13 - - - 0* standard");
+        assert_eq!(public.to_string().parse::<Position>().unwrap(), public);
+
+        let view = Position::from_view(&game.view(seat(3)));
+        assert!(view.cards(seat(0)).is_none());
+        assert_eq!(view.cards(seat(3)), Some(game.state().hand(seat(3))));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let errors = [
+            "3C/-/-/- - - - 0*".parse::<Position>(),
+            "3C/-/- - - - 0* standard".parse(),
+            "3X/-/-/- - - - 0* standard".parse(),
+            "3C/-/-/- 1:3D4D - - 0 standard".parse(),
+            "3C/-/-/- 4:3D - - 0 standard".parse(),
+            "3C/-/-/- - - - 0* house".parse(),
+            "3C/3C/-/- - - - 0* standard".parse(),
+            "3C/-/-/- 1:3C - - 0 standard".parse(),
+            "3C/-/-/- 1:3D 0 - 0 standard".parse(),
+            "99/13/13/13 - - - 0* standard".parse(),
+            "3C/-/-/- - - - 0 standard".parse(),
+            "3C/-/-/- - 11 - 2* standard".parse(),
+            "3C/-/-/- - - 33 0 standard".parse(),
+            "3C/-/-/4D 3:3D 1 - 2* standard".parse(),
+        ];
+        assert!(matches!(
+            errors,
+            [
+                Err(PositionError::WrongFieldCount(5)),
+                Err(PositionError::WrongSeatCount(3)),
+                Err(PositionError::BadCard(_)),
+                Err(PositionError::BadHand(_)),
+                Err(PositionError::BadSeat(_)),
+                Err(PositionError::BadRules(_)),
+                Err(PositionError::DuplicateCard(_)),
+                Err(PositionError::DuplicateCard(_)),
+                Err(PositionError::CannotMove(_)),
+                Err(PositionError::TooManyCards(seat)),
+                Err(PositionError::NothingToBeat),
+                Err(PositionError::RepeatedSeat(passed)),
+                Err(PositionError::RepeatedSeat(forfeited)),
+                Err(PositionError::PassedAtStartTrick(leading)),
+            ] if seat.index() == 0
+                && passed.index() == 1
+                && forfeited.index() == 3
+                && leading.index() == 1
+        ));
+    }
+}
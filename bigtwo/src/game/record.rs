@@ -0,0 +1,778 @@
+//! A text format for whole Games, for people to read, archive, discuss and edit, like PGN is for
+//! chess. A block of tags comes first, one per line, then the moves, one Trick per line:
+//!
+//! ```text
+//! [Date "2026-10-18"]
+//! [Seat0 "Ada"]
+//! [Seat1 "Player 2"]
+//! [Seat2 "Player 3"]
+//! [Seat3 "Player 4"]
+//! [Rules "standard"]
+//! [Seed "2024"]
+//! [Deal "3C4C5C6C7C8C9CTCJCQCKCAC2C/3D4D5D6D7D8D9DTDJDQDKDAD2D/3H.../3S..."]
+//! [Leader "0"]
+//! [Result "*"]
+//!
+//! {a comment on the whole Game}
+//! 1. 0:3C 1:4D 2:pass {a comment on the move before it} 3:5S 0:pass 1:pass
+//! 2. 3:6S 0:forfeit
+//! ```
+//!
+//! The Deal is written like the Cards in game::position, and each move is the Seat, then the
+//! Cards it played, "pass", "forfeit" or "undo". The Result is the winning Seat, or "*" while
+//! the Game is on, and Scores are the Cards left at each Seat once it is over. Only the Deal tag
+//! is needed, any tag this module does not know is kept as it is. A '"' in a tag, or a '}' in a
+//! comment, is escaped by a '\', as is a '\' itself, and a new line in a tag is written "\n".
+//! Comments are kept exactly as written, spaces and all, and a move may have any number of them.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::action::Action;
+use crate::card::set::CardSet;
+use crate::game::audit::{audit, Violation};
+use crate::game::log::{GameLog, LogEntry, ReplayError};
+use crate::game::position::{parse_cards, parse_seat, parse_seat_cards, write_cards, SeatCards};
+use crate::game::rules::Rules;
+use crate::game::state::GameState;
+use crate::game::{Game, NUM_PLAYERS};
+use crate::hand::Hand;
+use crate::player::seat::Seat;
+
+/// Represents the ways a string is not a GameRecord.
+#[derive(Debug)]
+pub enum RecordError {
+    /// The line at this number (starting from 1) is not a tag, i.e. `[Name "value"]`.
+    BadTag(usize),
+    /// The value of the named tag cannot be read.
+    BadValue(String, String),
+    /// There is no Deal tag, so the moves cannot be followed.
+    MissingDeal,
+    /// There is no Leader tag, and nobody was dealt the Three of Clubs to lead instead.
+    MissingLeader,
+    /// Tag names cannot be empty, or contain spaces, '"' or ']'.
+    BadTagName(String),
+    /// This is not a move, i.e. a Seat, ":" and then Cards, "pass", "forfeit" or "undo".
+    BadMove(String),
+    /// A comment is opened with "{" but never closed.
+    UnclosedComment,
+}
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BadTag(line) => write!(f, "line {} is not a tag", line),
+            Self::BadValue(name, value) => write!(f, "bad {} \"{}\"", name, value),
+            Self::MissingDeal => write!(f, "the record has no Deal tag"),
+            Self::MissingLeader => write!(f, "the record has no Leader tag, and no 3C is dealt"),
+            Self::BadTagName(name) => write!(f, "\"{}\" is not a tag name", name),
+            Self::BadMove(s) => write!(f, "\"{}\" is not a move", s),
+            Self::UnclosedComment => write!(f, "a comment is never closed"),
+        }
+    }
+}
+
+/// What a Seat did in one move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveKind {
+    /// The Seat took this Action.
+    Action(Action),
+    /// The Seat forfeited.
+    Forfeit,
+    /// The Seat took back its last Action, see Game::undo.
+    Undo,
+}
+
+/// One move of a GameRecord, with what was said about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub seat: Seat,
+    pub kind: MoveKind,
+    /// Every comment on the move, each exactly as written.
+    pub comments: Vec<String>,
+}
+
+impl Move {
+    /// The same move as a line of a GameLog.
+    fn to_entry(&self) -> LogEntry {
+        match self.kind {
+            MoveKind::Action(action) => LogEntry::Action {
+                seat: self.seat,
+                action,
+            },
+            MoveKind::Forfeit => LogEntry::Forfeit { seat: self.seat },
+            MoveKind::Undo => LogEntry::Undo { seat: self.seat },
+        }
+    }
+}
+
+impl Display for Move {
+    /// The move without its comments.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}:", self.seat)?;
+        match &self.kind {
+            MoveKind::Action(Action::Pass) => write!(f, "pass"),
+            MoveKind::Action(Action::Play(hand)) => write_cards(f, hand.cards().copied()),
+            MoveKind::Forfeit => write!(f, "forfeit"),
+            MoveKind::Undo => write!(f, "undo"),
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = RecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || RecordError::BadMove(s.to_string());
+        let (seat, what) = s.split_once(':').ok_or_else(bad)?;
+        let kind = match what {
+            "pass" => MoveKind::Action(Action::Pass),
+            "forfeit" => MoveKind::Forfeit,
+            "undo" => MoveKind::Undo,
+            _ => {
+                let cards = parse_cards(what).map_err(|_| bad())?;
+                MoveKind::Action(Action::Play(
+                    Hand::try_from_cards(&cards).map_err(|_| bad())?,
+                ))
+            }
+        };
+        Ok(Move {
+            seat: parse_seat(seat).map_err(|_| bad())?,
+            kind,
+            comments: Vec::new(),
+        })
+    }
+}
+
+/// A whole Game as written in the text format described at the top of this module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    /// When the Game was played, as written, e.g. "2026-10-18".
+    pub date: Option<String>,
+    /// The name of the Player at each Seat, at the index of that Seat.
+    pub names: [String; NUM_PLAYERS],
+    /// The house Rules the Game was played by.
+    pub rules: Rules,
+    /// The seed the Cards were shuffled with, if any.
+    pub seed: Option<u64>,
+    /// The Cards dealt to each Seat, at the index of that Seat.
+    pub deal: [CardSet; NUM_PLAYERS],
+    /// The Seat that led the first Trick.
+    pub leader: Seat,
+    /// The Seat that won, None while the Game is on.
+    pub result: Option<Seat>,
+    /// The Cards left at each Seat when the Game ended, None while the Game is on.
+    pub scores: Option<[usize; NUM_PLAYERS]>,
+    /// Any other tags, in the order they were written, see GameRecord::set_tag.
+    other_tags: Vec<(String, String)>,
+    /// Every comment on the whole Game, each exactly as written.
+    pub comments: Vec<String>,
+    /// Every move, one Line per Trick, see Game::tricks.
+    pub lines: Vec<Line>,
+}
+
+/// The moves of one Trick in a GameRecord.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// The number of the Trick, counting from 1 for the first Trick of the Game.
+    pub trick: usize,
+    pub moves: Vec<Move>,
+}
+
+impl GameRecord {
+    /// The record of this Game so far, without a date. Returns an Error if its log does not
+    /// replay, which only happens to a Game that has been tampered with.
+    pub fn from_game(game: &Game) -> Result<Self, ReplayError> {
+        let log = game.log();
+        let mut record = GameRecord {
+            date: None,
+            names: game.players.each_ref().map(|player| player.name.clone()),
+            rules: *game.rules(),
+            seed: game.seed(),
+            deal: [CardSet::new(); NUM_PLAYERS],
+            leader: Seat::default(),
+            result: None,
+            scores: None,
+            other_tags: Vec::new(),
+            comments: Vec::new(),
+            lines: Vec::new(),
+        };
+        if let Some(LogEntry::Deal { hands, leader, .. }) = log.entries().first() {
+            record.deal = hands.each_ref().map(|cards| cards.iter().collect());
+            record.leader = *leader;
+        }
+
+        // replay the moves one by one, to ask Game::tricks which Trick each one is part of
+        let mut replayed = Game::replay(&log.prefix(0))?;
+        for (i, entry) in log.entries().iter().enumerate().skip(1) {
+            // between Tricks, a move is part of the Trick the next lead starts, and an undo is
+            // part of the Trick of the Action it takes back
+            let tricks = replayed.tricks();
+            let next = tricks.len() + usize::from(tricks.current().is_none());
+            let (seat, kind, trick) = match entry {
+                LogEntry::Deal { .. } => return Err(ReplayError::UnexpectedDeal(i)),
+                LogEntry::Action { seat, action } => (*seat, MoveKind::Action(*action), next),
+                LogEntry::Forfeit { seat } => (*seat, MoveKind::Forfeit, next),
+                LogEntry::Undo { seat } => (*seat, MoveKind::Undo, tricks.len()),
+            };
+            replayed.replay_entry(i, entry)?;
+            record.push(
+                trick,
+                Move {
+                    seat,
+                    kind,
+                    comments: Vec::new(),
+                },
+            );
+        }
+
+        if game.state().is_over() {
+            record.result = game.state().standings().first().map(|s| s.seat);
+            record.scores = Some(game.state().hands().map(|hand| hand.len()));
+        }
+        Ok(record)
+    }
+
+    /// Adds the move to the Line of this Trick, starting a new Line if the last is of another.
+    fn push(&mut self, trick: usize, mv: Move) {
+        match self.lines.last_mut() {
+            Some(line) if line.trick == trick => line.moves.push(mv),
+            _ => self.lines.push(Line {
+                trick,
+                moves: alloc::vec![mv],
+            }),
+        }
+    }
+
+    /// Every move, in order.
+    pub fn moves(&self) -> impl Iterator<Item = &Move> + '_ {
+        self.lines.iter().flat_map(|line| &line.moves)
+    }
+
+    /// The deal and every move, as a GameLog.
+    pub fn to_log(&self) -> GameLog {
+        let deal = self.deal.map(|cards| cards.to_vec());
+        let mut log = GameLog::new(deal, self.leader, self.rules);
+        for mv in self.moves() {
+            log.push(mv.to_entry());
+        }
+        log
+    }
+
+    /// Plays the recorded Game again, checking every move, with the recorded names and seed.
+    pub fn replay(&self) -> Result<Game, ReplayError> {
+        let mut game = Game::replay(&self.to_log())?;
+        for (player, name) in game.players.iter_mut().zip(&self.names) {
+            player.name = name.clone();
+        }
+        game.seed = self.seed;
+        Ok(game)
+    }
+
+    /// Audits the recorded moves and Result, see game::audit.
+    pub fn audit(&self) -> Vec<Violation> {
+        audit(&self.to_log(), self.result)
+    }
+
+    /// Attaches a comment to the last move, or to the whole Game before the first move.
+    fn add_comment(&mut self, comment: String) {
+        let last = self.lines.last_mut().and_then(|line| line.moves.last_mut());
+        match last {
+            Some(mv) => mv.comments.push(comment),
+            None => self.comments.push(comment),
+        }
+    }
+
+    /// The tags this module does not know, in the order they were written or set.
+    pub fn other_tags(&self) -> &[(String, String)] {
+        &self.other_tags
+    }
+
+    /// Sets a tag as if it had been written in the record, so a tag this module knows, e.g.
+    /// "Seed", sets that field, and any other is added to the other tags.
+    pub fn set_tag(&mut self, name: &str, value: &str) -> Result<(), RecordError> {
+        let bad_name =
+            name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == '"' || c == ']');
+        if bad_name {
+            return Err(RecordError::BadTagName(name.to_string()));
+        }
+        self.read_tag(name, value.to_string())
+    }
+
+    /// Reads one tag into the record.
+    fn read_tag(&mut self, name: &str, value: String) -> Result<(), RecordError> {
+        let bad = || RecordError::BadValue(name.to_string(), value.clone());
+        let seat = name
+            .strip_prefix("Seat")
+            .and_then(|index| index.parse::<usize>().ok())
+            .and_then(|index| Seat::new(index).ok());
+        if let Some(seat) = seat {
+            self.names[seat.index()] = value;
+            return Ok(());
+        }
+        match name {
+            "Date" => self.date = Some(value),
+            "Rules" => self.rules = value.parse().map_err(|_| bad())?,
+            "Seed" => self.seed = Some(value.parse().map_err(|_| bad())?),
+            "Deal" => self.deal = parse_deal(&value).ok_or_else(bad)?,
+            "Leader" => self.leader = parse_seat(&value).map_err(|_| bad())?,
+            "Result" if value == "*" => self.result = None,
+            "Result" => self.result = Some(parse_seat(&value).map_err(|_| bad())?),
+            "Scores" => {
+                let scores: Vec<usize> = value
+                    .split_whitespace()
+                    .map(|score| score.parse().map_err(|_| bad()))
+                    .collect::<Result<_, _>>()?;
+                self.scores = Some(scores.try_into().map_err(|_| bad())?);
+            }
+            _ => self.other_tags.push((name.to_string(), value)),
+        }
+        Ok(())
+    }
+}
+
+/// The Cards at each Seat, separated by "/".
+fn parse_deal(value: &str) -> Option<[CardSet; NUM_PLAYERS]> {
+    let seats: Vec<&str> = value.split('/').collect();
+    let seats: [&str; NUM_PLAYERS] = seats.try_into().ok()?;
+    let mut deal = [CardSet::new(); NUM_PLAYERS];
+    for (cards, seat) in deal.iter_mut().zip(seats) {
+        match parse_seat_cards(seat).ok()? {
+            SeatCards::Known(known) => *cards = known,
+            SeatCards::Count(_) => return None,
+        }
+    }
+    Some(deal)
+}
+
+/// `[Name "value"]`, with any '"' or '\' in the value escaped by a '\', and new lines written
+/// "\n".
+fn parse_tag(line: &str) -> Option<(&str, String)> {
+    let inner = line.strip_prefix('[')?.strip_suffix(']')?;
+    let (name, value) = inner.split_once(' ')?;
+    let value = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => unescaped.push('\n'),
+                escaped => unescaped.push(escaped),
+            },
+            '"' => return None,
+            c => unescaped.push(c),
+        }
+    }
+    Some((name, unescaped))
+}
+
+fn write_tag(f: &mut core::fmt::Formatter, name: &str, value: &str) -> core::fmt::Result {
+    write!(f, "[{} \"", name)?;
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => write!(f, "\\{}", c)?,
+            '\n' => write!(f, "\\n")?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    writeln!(f, "\"]")
+}
+
+/// The comment up to the first '}' not escaped by a '\', and the text after that '}'.
+fn parse_comment(text: &str) -> Option<(String, &str)> {
+    let mut comment = String::new();
+    let mut chars = text.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => comment.push(chars.next()?.1),
+            '}' => return Some((comment, &text[i + 1..])),
+            c => comment.push(c),
+        }
+    }
+    None
+}
+
+/// `{comment}`, with any '}' or '\' in it escaped by a '\'.
+fn write_comment(f: &mut core::fmt::Formatter, comment: &str) -> core::fmt::Result {
+    write!(f, "{{")?;
+    for c in comment.chars() {
+        if c == '}' || c == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    write!(f, "}}")
+}
+
+impl Display for GameRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(date) = &self.date {
+            write_tag(f, "Date", date)?;
+        }
+        for (seat, name) in Seat::all().iter().zip(&self.names) {
+            write_tag(f, &alloc::format!("Seat{}", seat), name)?;
+        }
+        write_tag(f, "Rules", &self.rules.to_string())?;
+        if let Some(seed) = self.seed {
+            write_tag(f, "Seed", &seed.to_string())?;
+        }
+        let deal: Vec<String> = self
+            .deal
+            .iter()
+            .map(|cards| match cards.is_empty() {
+                true => "-".to_string(),
+                false => cards.iter().map(|card| card.to_string()).collect(),
+            })
+            .collect();
+        write_tag(f, "Deal", &deal.join("/"))?;
+        write_tag(f, "Leader", &self.leader.to_string())?;
+        match self.result {
+            Some(winner) => write_tag(f, "Result", &winner.to_string())?,
+            None => write_tag(f, "Result", "*")?,
+        }
+        if let Some(scores) = &self.scores {
+            let scores: Vec<String> = scores.iter().map(|score| score.to_string()).collect();
+            write_tag(f, "Scores", &scores.join(" "))?;
+        }
+        for (name, value) in &self.other_tags {
+            write_tag(f, name, value)?;
+        }
+
+        writeln!(f)?;
+        for comment in &self.comments {
+            write_comment(f, comment)?;
+            writeln!(f)?;
+        }
+        for line in &self.lines {
+            write!(f, "{}.", line.trick)?;
+            for mv in &line.moves {
+                write!(f, " {}", mv)?;
+                for comment in &mv.comments {
+                    write!(f, " ")?;
+                    write_comment(f, comment)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = RecordError;
+
+    /// Reads a record in the format described at the top of this module. Moves before the first
+    /// Trick number are taken to be of the first Trick.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut record = GameRecord {
+            date: None,
+            names: Seat::all().map(|seat| alloc::format!("Player {}", seat.index() + 1)),
+            rules: Rules::default(),
+            seed: None,
+            deal: [CardSet::new(); NUM_PLAYERS],
+            leader: Seat::default(),
+            result: None,
+            scores: None,
+            other_tags: Vec::new(),
+            comments: Vec::new(),
+            lines: Vec::new(),
+        };
+
+        let lines: Vec<&str> = text.lines().collect();
+        let mut moves_start = lines.len();
+        let (mut has_deal, mut has_leader) = (false, false);
+        for (i, line) in lines.iter().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            } else if !line.starts_with('[') {
+                moves_start = i;
+                break;
+            }
+            let (name, value) = parse_tag(line).ok_or(RecordError::BadTag(i + 1))?;
+            has_deal |= name == "Deal";
+            has_leader |= name == "Leader";
+            record.read_tag(name, value)?;
+        }
+        if !has_deal {
+            return Err(RecordError::MissingDeal);
+        }
+        if !has_leader {
+            // as dealt, whoever holds the Three of Clubs leads
+            record.leader =
+                GameState::three_of_clubs_holder(&record.deal).ok_or(RecordError::MissingLeader)?;
+        }
+
+        let moves = lines[moves_start..].join("\n");
+        let mut rest = moves.trim_start();
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix('{') {
+                let (comment, after) = parse_comment(after).ok_or(RecordError::UnclosedComment)?;
+                record.add_comment(comment);
+                rest = after;
+            } else {
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || c == '{')
+                    .unwrap_or(rest.len());
+                let (token, after) = rest.split_at(end);
+                let number = token
+                    .strip_suffix('.')
+                    .filter(|n| n.bytes().all(|b| b.is_ascii_digit()))
+                    .and_then(|n| n.parse().ok());
+                if let Some(trick) = number {
+                    record.lines.push(Line {
+                        trick,
+                        moves: Vec::new(),
+                    });
+                } else {
+                    let mv = token.parse()?;
+                    match record.lines.last_mut() {
+                        Some(line) => line.moves.push(mv),
+                        None => record.lines.push(Line {
+                            trick: 1,
+                            moves: alloc::vec![mv],
+                        }),
+                    }
+                }
+                rest = after;
+            }
+            rest = rest.trim_start();
+        }
+        // a number with no moves after it is not a Trick
+        record.lines.retain(|line| !line.moves.is_empty());
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dealer::prearranged::Prearranged;
+    use crate::game::audit::ViolationKind;
+    use crate::game::config::GameConfig;
+    use crate::tests::test_util::{in_order_game, seat};
+
+    /// The Deal tag of in_order_game.
+    const IN_ORDER: &str = "3C4C5C6C7C8C9CTCJCQCKCAC2C/3D4D5D6D7D8D9DTDJDQDKDAD2D/\
+                            3H4H5H6H7H8H9HTHJHQHKHAH2H/3S4S5S6S7S8S9STSJSQSKSAS2S";
+
+    #[test]
+    fn test_write() {
+        let mut game = in_order_game(&["3C", "4D", "", "5S", "", "", "6S"]);
+        game.forfeit(seat(0)).unwrap();
+        game.players[0].name = "Ada \"the Ace\"".into();
+
+        let mut record = GameRecord::from_game(&game).unwrap();
+        record.date = Some("2026-10-18".into());
+        record.lines[0].moves[1].comments = vec!["why not 3D?".into()];
+        let text = record.to_string();
+        assert_eq!(
+            text,
+            alloc::format!(
+                "[Date \"2026-10-18\"]\n\
+                 [Seat0 \"Ada \\\"the Ace\\\"\"]\n\
+                 [Seat1 \"Player 2\"]\n\
+                 [Seat2 \"Player 3\"]\n\
+                 [Seat3 \"Player 4\"]\n\
+                 [Rules \"standard\"]\n\
+                 [Deal \"{}\"]\n\
+                 [Leader \"0\"]\n\
+                 [Result \"*\"]\n\
+                 \n\
+                 1. 0:3C 1:4D {{why not 3D?}} 2:pass 3:5S 0:pass 1:pass\n\
+                 2. 3:6S 0:forfeit\n",
+                IN_ORDER
+            )
+        );
+        assert_eq!(text.parse::<GameRecord>().unwrap(), record);
+        assert_eq!(record.replay().unwrap().state(), game.state());
+    }
+
+    #[test]
+    fn test_forfeit_between_tricks() {
+        let mut game = Game::with_dealer(&mut Prearranged::in_order()).unwrap();
+        game.forfeit(seat(2)).unwrap();
+        for input in ["3C", "4D", "5S", "", ""] {
+            game.step(input).unwrap();
+        }
+        game.forfeit(seat(0)).unwrap();
+        game.step("6S").unwrap();
+
+        let record = GameRecord::from_game(&game).unwrap();
+        let text = record.to_string();
+        let lines: Vec<&str> = text
+            .lines()
+            .skip_while(|line| !line.starts_with("1."))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "1. 2:forfeit 0:3C 1:4D 3:5S 0:pass 1:pass",
+                "2. 0:forfeit 3:6S"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undo() {
+        let mut game = in_order_game(&["3C", "4D", "", "", ""]);
+        // taking back the pass that ended the first Trick
+        game.undo().unwrap();
+        game.step("").unwrap();
+        game.step("5D").unwrap();
+
+        let record = GameRecord::from_game(&game).unwrap();
+        let text = record.to_string();
+        let lines: Vec<&str> = text
+            .lines()
+            .skip_while(|line| !line.starts_with("1."))
+            .collect();
+        assert_eq!(
+            lines,
+            ["1. 0:3C 1:4D 2:pass 3:pass 0:pass 0:undo 0:pass", "2. 1:5D"]
+        );
+        let parsed: GameRecord = text.parse().unwrap();
+        assert_eq!(parsed, record);
+        assert!(parsed.audit().is_empty());
+        assert_eq!(parsed.replay().unwrap().state(), game.state());
+    }
+
+    #[test]
+    fn test_round_trip_finished_game() {
+        let mut game = GameConfig::new().seed(2024).build().unwrap();
+        while game.is_on() {
+            let action = game.get_npc_turn().unwrap();
+            game.apply(game.current_seat(), action).unwrap();
+        }
+        let record = GameRecord::from_game(&game).unwrap();
+        let winner = game.state().standings()[0].seat;
+        assert_eq!(record.result, Some(winner));
+        assert_eq!(record.scores.unwrap()[winner.index()], 0);
+        assert_eq!(record.lines.len(), game.tricks().len());
+        for (line, trick) in record.lines.iter().zip(game.tricks().tricks()) {
+            assert_eq!(line.moves.len(), trick.actions.len());
+        }
+
+        let parsed: GameRecord = record.to_string().parse().unwrap();
+        assert_eq!(parsed, record);
+        assert!(parsed.audit().is_empty());
+        let replayed = parsed.replay().unwrap();
+        assert_eq!(replayed.state(), game.state());
+        assert_eq!(replayed.seed(), Some(2024));
+    }
+
+    #[test]
+    fn test_read_hand_written() {
+        let text = alloc::format!(
+            "[Event \"Club night\"]\n\
+             [Deal \"{}\"]\n\
+             [Seat2 \"Bo\"]\n\
+             \n\
+             {{the opening}} 0:3C 1:4D\n\
+             {{risky}}\n\
+             2:pass 3:5S 0:pass 1:pass 7. 3:5S {{oops}}",
+            IN_ORDER
+        );
+        let record: GameRecord = text.parse().unwrap();
+        assert_eq!(record.names[2], "Bo");
+        assert_eq!(record.names[3], "Player 4");
+        assert_eq!(record.leader, seat(0));
+        assert_eq!(record.other_tags(), [("Event".into(), "Club night".into())]);
+        assert_eq!(record.comments, ["the opening"]);
+        assert_eq!(record.lines[0].moves[1].comments, ["risky"]);
+        assert_eq!(record.lines.len(), 2);
+        assert_eq!((record.lines[0].trick, record.lines[1].trick), (1, 7));
+
+        // 5S was played already
+        assert!(matches!(
+            record.audit()[..],
+            [
+                Violation {
+                    move_number: 7,
+                    kind: ViolationKind::IllegalAction(_)
+                },
+                ..
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_wrong_result() {
+        let deal = alloc::format!("[Deal \"{}\"]\n[Result \"2\"]\n1. 0:3C", IN_ORDER);
+        let record: GameRecord = deal.parse().unwrap();
+        assert!(matches!(
+            record.audit()[..],
+            [Violation {
+                kind: ViolationKind::WrongWinner { .. },
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_escapes() {
+        let mut game = in_order_game(&["3C"]);
+        game.players[1].name = "Bo \\ \"Bones\"\nthe second".into();
+        let mut record = GameRecord::from_game(&game).unwrap();
+        record.set_tag("Event", "Club\nnight").unwrap();
+        for name in ["Club Event", "", "Event\"", "Event]"] {
+            assert!(matches!(
+                record.set_tag(name, "x"),
+                Err(RecordError::BadTagName(_))
+            ));
+        }
+        record.set_tag("Seed", "12").unwrap();
+        assert_eq!(record.seed, Some(12));
+        record.comments = vec!["{a} \\{b}\nc".into()];
+        record.lines[0].moves[0].comments = vec!["}".into()];
+
+        let text = record.to_string();
+        assert!(text.contains("[Seat1 \"Bo \\\\ \\\"Bones\\\"\\nthe second\"]\n"));
+        assert!(text.contains("[Event \"Club\\nnight\"]\n"));
+        assert!(text.contains("{{a\\} \\\\{b\\}\nc}\n1. 0:3C {\\}}\n"));
+        assert_eq!(text.parse::<GameRecord>().unwrap(), record);
+    }
+
+    #[test]
+    fn test_comments_kept_as_written() {
+        let game = in_order_game(&["3C"]);
+        let mut record = GameRecord::from_game(&game).unwrap();
+        record.comments = vec!["".into(), "  ".into()];
+        record.lines[0].moves[0].comments = vec![" padded ".into(), "again".into()];
+
+        let text = record.to_string();
+        assert!(text.contains("{}\n{  }\n1. 0:3C { padded } {again}\n"));
+        assert_eq!(text.parse::<GameRecord>().unwrap(), record);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let deal = alloc::format!("[Deal \"{}\"]", IN_ORDER);
+        let errors = [
+            "[Seat0 \"Ada\"]".parse::<GameRecord>(),
+            "[Deal 3C]".parse(),
+            "[Deal \"3C/13/13/13\"]".parse(),
+            alloc::format!("[Seed \"x\"]\n{}", deal).parse(),
+            alloc::format!("{}\n1. 0:3C 1:4X", deal).parse(),
+            alloc::format!("{}\n1. 0:3C {{unclosed", deal).parse(),
+            alloc::format!("{}\n1. 0:3C {{escaped\\}}", deal).parse(),
+            "[Deal \"4C/5C/6C/7C\"]".parse(),
+        ];
+        assert!(matches!(
+            errors,
+            [
+                Err(RecordError::MissingDeal),
+                Err(RecordError::BadTag(1)),
+                Err(RecordError::BadValue(..)),
+                Err(RecordError::BadValue(..)),
+                Err(RecordError::BadMove(_)),
+                Err(RecordError::UnclosedComment),
+                Err(RecordError::UnclosedComment),
+                Err(RecordError::MissingLeader),
+            ]
+        ));
+    }
+}
@@ -0,0 +1,60 @@
+//! The rule variants a Game can be played with. Big Two is played with many house rules, these
+//! are the ones this crate supports so far.
+
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use core::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// The house rules of a Game, fixed when the Game is set up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Rules {
+    /// The first Hand of the Game must include the Three of Clubs, if its leader holds it.
+    /// Otherwise whoever holds it still leads, but may open with anything.
+    pub three_of_clubs_opens: bool,
+}
+
+impl Default for Rules {
+    /// The standard rules.
+    fn default() -> Self {
+        Rules {
+            three_of_clubs_opens: true,
+        }
+    }
+}
+
+impl Display for Rules {
+    /// Names the variant, "standard" for the default Rules.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.three_of_clubs_opens {
+            write!(f, "standard")
+        } else {
+            write!(f, "free-opening")
+        }
+    }
+}
+
+/// Returned when parsing Rules that are not one of the named variants.
+#[derive(Debug)]
+pub struct ParseRulesError(pub String);
+
+impl Display for ParseRulesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "unknown rules \"{}\"", self.0)
+    }
+}
+
+impl FromStr for Rules {
+    type Err = ParseRulesError;
+    /// Reads the name of a variant, as written by Display.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "standard" => Ok(Rules::default()),
+            "free-opening" => Ok(Rules {
+                three_of_clubs_opens: false,
+            }),
+            _ => Err(ParseRulesError(name.to_string())),
+        }
+    }
+}
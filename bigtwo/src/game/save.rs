@@ -0,0 +1,293 @@
+//! Saves a Game in progress as versioned JSON, and loads it again to continue.
+//! Loading replays the saved log, so a tampered or corrupted save cannot produce an impossible
+//! Game.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt::Display;
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use crate::game::clock::SystemClock;
+use crate::game::clock::{Clock, TimeControl, Timer};
+use crate::game::log::{GameLog, ReplayError};
+use crate::game::state::GameState;
+use crate::game::{Game, NUM_PLAYERS};
+use crate::player::Player;
+
+/// The version of the save format written by this version of the crate. Bump it whenever the
+/// format changes, and teach Game::load_json to read the older versions.
+pub const SAVE_VERSION: u32 = 2;
+
+/// Everything needed to continue a Game, as written to disk.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SavedGame {
+    /// The SAVE_VERSION this was written with.
+    pub version: u32,
+    /// The seed the Cards were shuffled with, if any.
+    pub seed: Option<u64>,
+    /// Who is playing, and their Cards.
+    pub players: [Player; NUM_PLAYERS],
+    /// The deal and every Action since.
+    pub log: GameLog,
+    /// Whose turn it is and what there is to beat, must agree with replaying the log.
+    pub state: GameState,
+    /// The time control and what was left of the time, None if the Game is not on the clock.
+    /// New in version 2.
+    #[serde(default)]
+    pub timer: Option<SavedTimer>,
+}
+
+/// A Game's TimeControl, and what was left of each Seat's time when it was saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedTimer {
+    pub control: TimeControl,
+    /// What was left of each Seat's bank, at the index of that Seat.
+    pub banks: [Duration; NUM_PLAYERS],
+    /// How long the current turn had taken.
+    pub elapsed: Duration,
+}
+
+/// Represents the ways a saved Game can fail to load.
+#[derive(Debug)]
+pub enum LoadError {
+    /// Saved by a version of the format this crate cannot read.
+    UnsupportedVersion(u32),
+    /// Not valid JSON, or not a saved Game.
+    Json(serde_json::Error),
+    /// The saved log does not replay.
+    Replay(ReplayError),
+    /// The saved state, or a Player's Cards, disagree with replaying the saved log.
+    Mismatch,
+    /// The Game was saved on the clock, and there is no Clock to carry on with, see
+    /// Game::from_saved_with_clock.
+    NoClock,
+}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+impl From<ReplayError> for LoadError {
+    fn from(e: ReplayError) -> Self {
+        Self::Replay(e)
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedVersion(v) => {
+                write!(
+                    f,
+                    "save format version {v} is not supported, expected {SAVE_VERSION} or older"
+                )
+            }
+            Self::Json(e) => write!(f, "not a saved game: {}", e),
+            Self::Replay(e) => write!(f, "ReplayError! {}", e),
+            Self::Mismatch => write!(f, "the saved game does not match its own history"),
+            Self::NoClock => write!(f, "the saved game is on the clock, and there is no clock"),
+        }
+    }
+}
+
+/// Only the version, so that it can be checked before trying to read the rest.
+#[derive(Deserialize)]
+struct Version {
+    version: u32,
+}
+
+impl Game {
+    /// Everything needed to continue this Game later.
+    pub fn to_saved(&self) -> SavedGame {
+        SavedGame {
+            version: SAVE_VERSION,
+            seed: self.seed,
+            players: self.players.clone(),
+            log: self.log.clone(),
+            state: self.state,
+            timer: self.timer.as_ref().map(Timer::to_saved),
+        }
+    }
+
+    /// Continues a saved Game, after checking it against its own history.
+    /// Observers are not saved, they have to be added again. A Game saved on the clock carries
+    /// on with the system's clock, see Game::from_saved_with_clock, which without the std
+    /// feature is the only way to load one.
+    pub fn from_saved(saved: SavedGame) -> Result<Game, LoadError> {
+        #[cfg(feature = "std")]
+        let clock: Option<Box<dyn Clock>> = Some(Box::new(SystemClock::default()));
+        #[cfg(not(feature = "std"))]
+        let clock = None;
+        Game::restore(saved, clock)
+    }
+
+    /// Continues a saved Game like Game::from_saved, with this Clock if it was on the clock.
+    /// The turn underway carries on from the time it had taken when it was saved.
+    pub fn from_saved_with_clock<C: Clock + 'static>(
+        saved: SavedGame,
+        clock: C,
+    ) -> Result<Game, LoadError> {
+        Game::restore(saved, Some(Box::new(clock)))
+    }
+
+    fn restore(saved: SavedGame, clock: Option<Box<dyn Clock>>) -> Result<Game, LoadError> {
+        if saved.version == 0 || saved.version > SAVE_VERSION {
+            return Err(LoadError::UnsupportedVersion(saved.version));
+        }
+        let mut game = Game::replay(&saved.log)?;
+        let same_cards = game
+            .players
+            .iter()
+            .zip(&saved.players)
+            .all(|(replayed, saved)| replayed.cards == saved.cards && saved.seat == replayed.seat);
+        if game.state != saved.state || !same_cards {
+            return Err(LoadError::Mismatch);
+        }
+        if let Some(timer) = saved.timer {
+            let clock = clock.ok_or(LoadError::NoClock)?;
+            game.timer = Some(Timer::from_saved(timer, clock));
+        }
+        game.players = saved.players;
+        game.seed = saved.seed;
+        Ok(game)
+    }
+
+    /// The saved Game as pretty printed JSON, see Game::load_json.
+    pub fn save_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self.to_saved())
+    }
+
+    /// Continues a Game saved by Game::save_json, see Game::from_saved.
+    pub fn load_json(json: &str) -> Result<Game, LoadError> {
+        Game::from_saved(read_saved(json)?)
+    }
+
+    /// Continues a Game saved by Game::save_json, see Game::from_saved_with_clock.
+    pub fn load_json_with_clock<C: Clock + 'static>(
+        json: &str,
+        clock: C,
+    ) -> Result<Game, LoadError> {
+        Game::from_saved_with_clock(read_saved(json)?, clock)
+    }
+}
+
+/// Reads a SavedGame, checking its version first.
+fn read_saved(json: &str) -> Result<SavedGame, LoadError> {
+    let Version { version } = serde_json::from_str(json)?;
+    if version == 0 || version > SAVE_VERSION {
+        return Err(LoadError::UnsupportedVersion(version));
+    }
+    Ok(serde_json::from_str(json)?)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::action::Action;
+    use crate::game::clock::ManualClock;
+    use crate::player::controller::Controller;
+    use crate::tests::test_util::played_game;
+
+    /// A few turns in, with a named Remote Player at Seat 2.
+    fn named_game() -> Game {
+        let mut game = played_game(99, 10);
+        game.players[2].name = String::from("Grace");
+        game.players[2].controller = Controller::Remote;
+        game
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let game = named_game();
+        let json = game.save_json().unwrap();
+        let mut loaded = Game::load_json(&json).unwrap();
+
+        assert_eq!(loaded.state(), game.state());
+        assert_eq!(loaded.log(), game.log());
+        assert_eq!(loaded.seed(), Some(99));
+        assert_eq!(loaded.played_hands, game.played_hands);
+        assert_eq!(loaded.players[2].name, "Grace");
+        assert_eq!(loaded.players[2].controller, Controller::Remote);
+
+        // it can be continued, and undone
+        let action = loaded.get_npc_turn().unwrap();
+        assert!(loaded.apply(loaded.current_seat(), action).is_ok());
+        assert!(loaded.undo().is_some());
+        assert!(loaded.undo().is_some());
+    }
+
+    #[test]
+    fn test_save_and_load_on_the_clock() {
+        let mut game = Game::with_seed(99);
+        let clock = ManualClock::new();
+        let control = TimeControl {
+            bank: Some(Duration::from_secs(10)),
+            ..TimeControl::per_turn(Duration::from_secs(5))
+        };
+        game.set_time_control(control, clock.clone());
+        let first = game.current_seat();
+        clock.advance(Duration::from_secs(8));
+        game.step("3C").unwrap();
+        let second = game.current_seat();
+        clock.advance(Duration::from_secs(3));
+
+        let json = game.save_json().unwrap();
+        let clock = ManualClock::new();
+        let mut loaded = Game::load_json_with_clock(&json, clock.clone()).unwrap();
+        assert_eq!(loaded.time_control(), Some(&control));
+        assert_eq!(loaded.bank(first), Some(Duration::from_secs(7)));
+        // the turn underway carries on where it left off
+        assert_eq!(loaded.time_left(second), Some(Duration::from_secs(12)));
+        clock.advance(Duration::from_secs(12));
+        assert!(loaded.is_out_of_time());
+        assert!(loaded.apply(second, Action::Pass).is_err());
+
+        // without a Clock of its own, it carries on with the system's
+        #[cfg(not(feature = "std"))]
+        assert!(matches!(Game::load_json(&json), Err(LoadError::NoClock)));
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Game::load_json(&json).unwrap().time_control(),
+            Some(&control)
+        );
+    }
+
+    #[test]
+    fn test_load_version_1() {
+        let game = named_game();
+        let mut saved = game.to_saved();
+        saved.version = 1;
+        let mut json: serde_json::Value = serde_json::to_value(&saved).unwrap();
+        json.as_object_mut().unwrap().remove("timer");
+        let loaded = Game::load_json(&json.to_string()).unwrap();
+        assert_eq!(loaded.state(), game.state());
+        assert!(loaded.time_control().is_none());
+    }
+
+    #[test]
+    fn test_load_checks() {
+        let game = named_game();
+
+        let mut saved = game.to_saved();
+        saved.version = SAVE_VERSION + 1;
+        let json = serde_json::to_string(&saved).unwrap();
+        assert!(matches!(
+            Game::load_json(&json),
+            Err(LoadError::UnsupportedVersion(_))
+        ));
+
+        // somebody helped themselves to an extra card
+        let mut saved = game.to_saved();
+        let card = saved.players[1].cards.pop().unwrap();
+        saved.players[0].cards.push(card);
+        assert!(matches!(Game::from_saved(saved), Err(LoadError::Mismatch)));
+
+        assert!(matches!(Game::load_json("{}"), Err(LoadError::Json(_))));
+    }
+}
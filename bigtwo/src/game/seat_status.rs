@@ -0,0 +1,145 @@
+//! Players coming and going during a Game: they may drop out and come back, or hand their Seat to
+//! a bot and reclaim it later, all without the Game ending. See Game::forfeit for leaving for
+//! good.
+
+use core::fmt::Display;
+
+use crate::game::observer::GameEvent;
+use crate::game::Game;
+use crate::player::controller::{BotKind, Controller};
+use crate::player::seat::Seat;
+use crate::player::status::SeatStatus;
+
+/// Represents the ways a Seat's status cannot be changed as requested.
+#[derive(Debug)]
+pub enum SeatError {
+    /// The Seat has forfeited, it takes no further part in the Game.
+    Forfeited(Seat),
+    /// The Seat's status does not allow this, e.g. reclaiming a Seat no bot has taken over.
+    WrongStatus(Seat, SeatStatus),
+}
+
+impl Display for SeatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Forfeited(seat) => write!(f, "seat {} has forfeited", seat),
+            Self::WrongStatus(seat, status) => write!(f, "seat {} is {}", seat, status),
+        }
+    }
+}
+
+impl Game {
+    /// Whether the Player at this Seat is still taking part.
+    pub fn seat_status(&self, seat: Seat) -> SeatStatus {
+        self.player(seat).status
+    }
+
+    /// Marks the Player at this Seat as having lost their connection. Their turns wait for them
+    /// to reconnect, or for a bot to take over, or for their time to run out.
+    pub fn disconnect(&mut self, seat: Seat) -> Result<(), SeatError> {
+        match self.seat_status(seat) {
+            SeatStatus::Present => {
+                self.set_status(seat, SeatStatus::Disconnected);
+                Ok(())
+            }
+            status => Err(self.status_error(seat, status)),
+        }
+    }
+
+    /// The disconnected Player at this Seat is back.
+    pub fn reconnect(&mut self, seat: Seat) -> Result<(), SeatError> {
+        match self.seat_status(seat) {
+            SeatStatus::Disconnected => {
+                self.set_status(seat, SeatStatus::Present);
+                Ok(())
+            }
+            status => Err(self.status_error(seat, status)),
+        }
+    }
+
+    /// A bot of this kind plays for the Player at this Seat from now on, carrying on with the
+    /// Cards they hold, until they reclaim the Seat.
+    pub fn take_over(&mut self, seat: Seat, kind: BotKind) -> Result<(), SeatError> {
+        match self.seat_status(seat) {
+            SeatStatus::Present | SeatStatus::Disconnected => {
+                let player = &mut self.players[seat.index()];
+                let owner = core::mem::replace(&mut player.controller, Controller::Bot(kind));
+                self.set_status(seat, SeatStatus::TakenOver(owner));
+                Ok(())
+            }
+            status => Err(self.status_error(seat, status)),
+        }
+    }
+
+    /// The Player whose Seat was taken over by a bot plays for themselves again.
+    pub fn reclaim(&mut self, seat: Seat) -> Result<(), SeatError> {
+        match self.seat_status(seat) {
+            SeatStatus::TakenOver(owner) => {
+                self.players[seat.index()].controller = owner;
+                self.set_status(seat, SeatStatus::Present);
+                Ok(())
+            }
+            status => Err(self.status_error(seat, status)),
+        }
+    }
+
+    fn set_status(&mut self, seat: Seat, status: SeatStatus) {
+        self.players[seat.index()].status = status;
+        self.notify(&GameEvent::SeatStatusChanged(seat, status));
+    }
+
+    fn status_error(&self, seat: Seat, status: SeatStatus) -> SeatError {
+        match status {
+            SeatStatus::Forfeited => SeatError::Forfeited(seat),
+            status => SeatError::WrongStatus(seat, status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_take_over_and_reclaim() {
+        let mut game = Game::with_seed(8);
+        let seat = game.players[0].seat;
+        assert_eq!(game.players[0].controller, Controller::Human);
+        game.disconnect(seat).unwrap();
+        assert!(matches!(
+            game.disconnect(seat),
+            Err(SeatError::WrongStatus(_, SeatStatus::Disconnected))
+        ));
+
+        // the bot plays on with the Seat's Cards, until its Player is back
+        game.take_over(seat, BotKind::Basic).unwrap();
+        assert_eq!(game.players[0].controller, Controller::Bot(BotKind::Basic));
+        while game.current_seat() != seat {
+            let action = game.get_npc_turn().unwrap();
+            game.apply(game.current_seat(), action).unwrap();
+        }
+        let action = game.get_npc_turn().unwrap();
+        assert!(game.legal_actions(seat).contains(&action));
+        game.apply(seat, action).unwrap();
+
+        game.reclaim(seat).unwrap();
+        assert_eq!(game.seat_status(seat), SeatStatus::Present);
+        assert_eq!(game.players[0].controller, Controller::Human);
+        assert!(game.reclaim(seat).is_err());
+    }
+
+    #[test]
+    fn test_forfeited_seats_stay_forfeited() {
+        let mut game = Game::with_seed(8);
+        let seat = game.players[1].seat;
+        game.forfeit(seat).unwrap();
+        assert_eq!(game.seat_status(seat), SeatStatus::Forfeited);
+        assert!(game.is_on());
+        assert!(matches!(
+            game.take_over(seat, BotKind::Basic),
+            Err(SeatError::Forfeited(_))
+        ));
+        assert!(matches!(game.reconnect(seat), Err(SeatError::Forfeited(_))));
+    }
+}
@@ -0,0 +1,449 @@
+//! The rules-relevant position of a Game, small enough to copy for every explored move.
+
+use alloc::vec::Vec;
+
+use crate::action::Action;
+use crate::card::set::CardSet;
+use crate::card::THREE_OF_CLUBS;
+use crate::dealer::DealError;
+use crate::game::check_player_can_play_hand::check_player_can_play_hand;
+use crate::game::next_player_id::next_player_id;
+use crate::game::rules::Rules;
+use crate::game::{legal_actions, zobrist, GameStepError, NUM_PLAYERS};
+use crate::hand::Hand;
+use crate::player::seat::{Seat, SeatSet};
+
+use serde::{Deserialize, Serialize};
+
+/// Everything the rules need to decide what may happen next, and nothing else (no names, no
+/// history, no I/O). It is Copy, so search algorithms can explore successor states freely with
+/// GameState::apply, which never changes the state it is called on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GameState {
+    /// The Cards still held at each Seat, at the index of that Seat.
+    hands: [CardSet; NUM_PLAYERS],
+
+    /// The Seat whose turn it is.
+    current_seat: Seat,
+
+    /// The Hand to beat and the Seat that played it, None until the first Hand of the Game.
+    last_play: Option<(Seat, Hand)>,
+
+    /// The Seats that have passed so far this Trick.
+    passed: SeatSet,
+
+    /// True when the current Seat may lead anything, i.e. everybody else passed.
+    is_start_trick: bool,
+
+    /// The Seats that have forfeited, they take no further part and finish last.
+    #[serde(default)]
+    forfeited: SeatSet,
+
+    /// The house Rules, the same for the whole Game, so they are not part of the hash.
+    #[serde(default)]
+    rules: Rules,
+
+    /// The Zobrist hash of all of the above, updated with every apply.
+    hash: u64,
+}
+
+impl GameState {
+    /// The state before the first play, the Seat holding the Three of Clubs leads. Returns an
+    /// Error if nobody holds it, see GameState::with_leader to choose the leader instead.
+    pub fn new(hands: [CardSet; NUM_PLAYERS]) -> Result<Self, DealError> {
+        let leader = GameState::three_of_clubs_holder(&hands).ok_or(DealError::NoThreeOfClubs)?;
+        Ok(GameState::with_leader(hands, leader))
+    }
+
+    /// The Seat holding the Three of Clubs, if anybody does.
+    pub fn three_of_clubs_holder(hands: &[CardSet; NUM_PLAYERS]) -> Option<Seat> {
+        Seat::all()
+            .into_iter()
+            .find(|seat| hands[seat.index()].contains(&THREE_OF_CLUBS))
+    }
+
+    /// The state before the first play, with this Seat to lead. It must open with the Three of
+    /// Clubs if it holds it, otherwise it may open with anything.
+    pub fn with_leader(hands: [CardSet; NUM_PLAYERS], leader: Seat) -> Self {
+        GameState::with_rules(hands, leader, Rules::default())
+    }
+
+    /// The state before the first play, with this Seat to lead, played by these Rules.
+    pub fn with_rules(hands: [CardSet; NUM_PLAYERS], leader: Seat, rules: Rules) -> Self {
+        let mut state = GameState {
+            hands,
+            current_seat: leader,
+            last_play: None,
+            passed: SeatSet::new(),
+            is_start_trick: true,
+            forfeited: SeatSet::new(),
+            rules,
+            hash: 0,
+        };
+        state.hash = zobrist::hash(&state);
+        state
+    }
+
+    /// A state in the middle of a Game, e.g. a puzzle, whose Cards need not add up to a full
+    /// Deck. Nothing is checked here, see Position::to_state.
+    pub(crate) fn from_parts(
+        hands: [CardSet; NUM_PLAYERS],
+        current_seat: Seat,
+        last_play: Option<(Seat, Hand)>,
+        passed: SeatSet,
+        forfeited: SeatSet,
+        is_start_trick: bool,
+        rules: Rules,
+    ) -> Self {
+        let mut state = GameState {
+            hands,
+            current_seat,
+            last_play,
+            passed,
+            is_start_trick,
+            forfeited,
+            rules,
+            hash: 0,
+        };
+        state.hash = zobrist::hash(&state);
+        state
+    }
+
+    /// The Cards still held at this Seat.
+    pub fn hand(&self, seat: Seat) -> &CardSet {
+        &self.hands[seat.index()]
+    }
+
+    /// The Cards still held at every Seat, at the index of that Seat.
+    pub fn hands(&self) -> &[CardSet; NUM_PLAYERS] {
+        &self.hands
+    }
+
+    /// The Seat whose turn it is.
+    pub fn current_seat(&self) -> Seat {
+        self.current_seat
+    }
+
+    /// The most recently played Hand and who played it, None until the first Hand of the Game.
+    pub fn last_play(&self) -> Option<(Seat, Hand)> {
+        self.last_play
+    }
+
+    /// The most recently played Hand, None until the first Hand of the Game.
+    pub fn last_hand(&self) -> Option<&Hand> {
+        self.last_play.as_ref().map(|(_, hand)| hand)
+    }
+
+    /// The Seats that have passed so far this Trick.
+    pub fn passed(&self) -> SeatSet {
+        self.passed
+    }
+
+    /// True when the current Seat may lead anything.
+    pub fn is_start_trick(&self) -> bool {
+        self.is_start_trick
+    }
+
+    /// The Seats that have forfeited the Game.
+    pub fn forfeited(&self) -> SeatSet {
+        self.forfeited
+    }
+
+    /// The house Rules the Game is played by.
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// The Zobrist hash of this state, equal states always have equal hashes no matter how they
+    /// were reached, so it can key a transposition table.
+    pub fn zobrist(&self) -> u64 {
+        self.hash
+    }
+
+    /// True once any Seat has played all of its Cards, or everybody else has forfeited.
+    pub fn is_over(&self) -> bool {
+        self.hands.iter().any(CardSet::is_empty) || self.forfeited.len() >= NUM_PLAYERS - 1
+    }
+
+    /// Every Action the current Seat may take, empty once the Game is over.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if self.is_over() {
+            return Vec::new();
+        }
+        legal_actions(
+            self.hand(self.current_seat),
+            self.last_hand(),
+            self.is_start_trick,
+            &self.rules,
+        )
+    }
+
+    /// The state after the current Seat takes this Action, or an Error if the Action is not
+    /// allowed. Never changes this state.
+    pub fn apply(&self, action: &Action) -> Result<GameState, GameStepError> {
+        if self.is_over() {
+            return Err(GameStepError::GameOver);
+        }
+        let mut next = *self;
+        let seat = self.current_seat;
+        check_player_can_play_hand(
+            self.last_hand(),
+            self.hand(seat),
+            action,
+            self.is_start_trick,
+            &self.rules,
+        )?;
+
+        match action {
+            Action::Pass => {
+                next.passed.insert(seat);
+                next.hash ^= zobrist::passed(seat);
+            }
+            Action::Play(hand) => {
+                let cards = CardSet::from_hand(hand);
+                next.hands[seat.index()] = self.hand(seat).difference(&cards);
+                for card in cards {
+                    next.hash ^= zobrist::held(seat, &card);
+                }
+                if let Some((last_seat, last_hand)) = &self.last_play {
+                    next.hash ^= zobrist::last_play(*last_seat, last_hand);
+                }
+                next.last_play = Some((seat, *hand));
+                next.hash ^= zobrist::last_play(seat, hand);
+            }
+        }
+
+        // advance to next player, skipping any player that has already passed or forfeited
+        next.current_seat = next_player_id(seat, &next.passed.union(&next.forfeited))?;
+        next.hash ^= zobrist::turn(seat) ^ zobrist::turn(next.current_seat);
+
+        // if N-1/N players have passed (or forfeited), start new trick
+        next.is_start_trick = next.passed.len() + next.forfeited.len() == NUM_PLAYERS - 1;
+        next.end_trick_if_started(self.is_start_trick);
+        Ok(next)
+    }
+
+    /// The state after this Seat forfeits, whether or not it is their turn. The Game carries on
+    /// without them, and ends once only one Seat is left. Never changes this state.
+    pub fn forfeit(&self, seat: Seat) -> Result<GameState, GameStepError> {
+        if self.is_over() {
+            return Err(GameStepError::GameOver);
+        }
+        if self.forfeited.contains(&seat) {
+            return Err(GameStepError::Forfeited(seat));
+        }
+        let mut next = *self;
+        next.forfeited.insert(seat);
+        next.hash ^= zobrist::forfeited(seat);
+        if next.passed.remove(&seat) {
+            next.hash ^= zobrist::passed(seat);
+        }
+        if next.is_over() {
+            return Ok(next);
+        }
+
+        let out = next.passed.union(&next.forfeited);
+        if seat == self.current_seat {
+            next.current_seat = next_player_id(seat, &out)?;
+            next.hash ^= zobrist::turn(seat) ^ zobrist::turn(next.current_seat);
+        }
+        // with only the current Seat left in the Trick, it leads the next one
+        next.is_start_trick = self.is_start_trick || out.len() == NUM_PLAYERS - 1;
+        next.end_trick_if_started(self.is_start_trick);
+        Ok(next)
+    }
+
+    /// Clears the passes if a new Trick has just started, and keeps the hash up to date.
+    fn end_trick_if_started(&mut self, was_start_trick: bool) {
+        if self.is_start_trick {
+            for passed in self.passed.iter() {
+                self.hash ^= zobrist::passed(passed);
+            }
+            self.passed.clear();
+        }
+        if self.is_start_trick != was_start_trick {
+            self.hash ^= zobrist::start_trick();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::game::PlayHandError;
+    use crate::tests::test_util::vec_card_from_str;
+
+    fn state() -> GameState {
+        GameState::new([
+            vec_card_from_str("4C 5C").into(),
+            vec_card_from_str("3C 6C").into(),
+            vec_card_from_str("4D 7C").into(),
+            vec_card_from_str("4H 8C").into(),
+        ])
+        .unwrap()
+    }
+
+    fn play(hand: &str) -> Action {
+        hand.parse().unwrap()
+    }
+
+    #[test]
+    fn test_apply_leaves_state_untouched() {
+        let start = state();
+        assert_eq!(start.current_seat().index(), 1);
+
+        let next = start.apply(&play("3C")).unwrap();
+        assert_eq!(start, state());
+        assert_ne!(next, start);
+        assert_eq!(next.current_seat().index(), 2);
+        assert_eq!(next.hand(Seat::new(1).unwrap()).to_string(), "6C");
+        assert_eq!(
+            next.last_play(),
+            Some((Seat::new(1).unwrap(), "3C".parse().unwrap()))
+        );
+
+        // illegal Actions leave no trace either
+        assert!(next.apply(&Action::Pass).is_ok());
+        assert!(next.apply(&play("4H")).is_err());
+        assert!(matches!(
+            start.apply(&play("6C")),
+            Err(GameStepError::PlayHandError(
+                PlayHandError::NotThreeOfClubsToStartGame
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_zobrist() {
+        let start = state();
+        assert_eq!(start.zobrist(), zobrist::hash(&start));
+
+        // every state along the way keeps its hash up to date
+        let mut states = vec![start];
+        for action in ["3C", "", "", "", "6C"] {
+            let next = states.last().unwrap().apply(&play(action)).unwrap();
+            assert_eq!(next.zobrist(), zobrist::hash(&next));
+            states.push(next);
+        }
+        let mut hashes: Vec<u64> = states.iter().map(GameState::zobrist).collect();
+        hashes.sort();
+        hashes.dedup();
+        assert_eq!(hashes.len(), states.len());
+    }
+
+    #[test]
+    fn test_zobrist_transposition() {
+        // Seat 2 follows Seat 1's two tricks with the same two Cards, in either order
+        let hands = [
+            vec_card_from_str("4C 5C").into(),
+            vec_card_from_str("3C 3D 2H 2S 5S").into(),
+            vec_card_from_str("4D 7C 9D").into(),
+            vec_card_from_str("4H 8C").into(),
+        ];
+        let run = |actions: &[&str]| {
+            actions
+                .iter()
+                .try_fold(GameState::new(hands).unwrap(), |state, action| {
+                    state.apply(&play(action))
+                })
+                .unwrap()
+        };
+        let a = run(&["3C", "4D", "", "", "2S", "", "3D", "7C", "", "", "2H", ""]);
+        let b = run(&["3C", "7C", "", "", "2S", "", "3D", "4D", "", "", "2H", ""]);
+        assert_eq!(a.current_seat(), b.current_seat());
+        assert_eq!(a.hands(), b.hands());
+        assert_eq!(a.zobrist(), b.zobrist());
+    }
+
+    #[test]
+    fn test_with_leader() {
+        let hands = *state().hands();
+        let leader = Seat::new(2).unwrap();
+        let state = GameState::with_leader(hands, leader);
+        assert_eq!(state.current_seat(), leader);
+        assert!(state.apply(&play("7C")).is_ok());
+        assert!(state.apply(&Action::Pass).is_err());
+        assert_eq!(state.zobrist(), zobrist::hash(&state));
+
+        // without the Three of Clubs nobody leads, unless told to
+        let mut hands = hands;
+        hands[1].remove(&THREE_OF_CLUBS);
+        assert!(matches!(
+            GameState::new(hands),
+            Err(DealError::NoThreeOfClubs)
+        ));
+    }
+
+    #[test]
+    fn test_legal_actions_apply() {
+        // every legal Action applies cleanly, all the way to the end of the Game
+        let mut state = state();
+        while !state.is_over() {
+            let actions = state.legal_actions();
+            for action in &actions {
+                assert!(state.apply(action).is_ok(), "{action}");
+            }
+            state = state.apply(actions.last().unwrap()).unwrap();
+        }
+        assert!(state.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn test_apply_trick_and_game_over() {
+        let state = state()
+            .apply(&play("3C"))
+            .and_then(|s| s.apply(&Action::Pass))
+            .and_then(|s| s.apply(&Action::Pass))
+            .and_then(|s| s.apply(&Action::Pass))
+            .unwrap();
+        // everybody else passed, so Seat 1 leads a new trick
+        assert_eq!(state.current_seat().index(), 1);
+        assert!(state.is_start_trick());
+        assert!(state.passed().is_empty());
+        assert!(!state.is_over());
+
+        let state = state.apply(&play("6C")).unwrap();
+        assert!(state.is_over());
+        assert!(matches!(
+            state.apply(&Action::Pass),
+            Err(GameStepError::GameOver)
+        ));
+    }
+
+    #[test]
+    fn test_forfeit() {
+        let seat = |index| Seat::new(index).unwrap();
+
+        // the leader forfeits before opening, the next Seat may open with anything
+        let start = state().forfeit(seat(1)).unwrap();
+        assert_eq!(start.current_seat(), seat(2));
+        assert!(start.is_start_trick());
+        assert!(start.apply(&play("7C")).is_ok());
+        assert_eq!(start.zobrist(), zobrist::hash(&start));
+
+        // the Seat with the Hand to beat forfeits, the Trick goes on without them
+        let state = state().apply(&play("3C")).unwrap();
+        let state = state.forfeit(seat(1)).unwrap();
+        assert_eq!(state.current_seat(), seat(2));
+        assert!(!state.is_start_trick());
+        let state = state
+            .apply(&Action::Pass)
+            .and_then(|s| s.apply(&Action::Pass))
+            .unwrap();
+        // and is skipped from then on
+        assert!(state.is_start_trick());
+        assert_eq!(state.current_seat(), seat(0));
+        assert_eq!(state.zobrist(), zobrist::hash(&state));
+        assert!(matches!(
+            state.forfeit(seat(1)),
+            Err(GameStepError::Forfeited(_))
+        ));
+
+        // the last Seat left wins, the forfeits finish last
+        let state = state.forfeit(seat(2)).unwrap().forfeit(seat(0)).unwrap();
+        assert!(state.is_over());
+        let standings: Vec<usize> = state.standings().iter().map(|s| s.seat.index()).collect();
+        assert_eq!(standings, [3, 1, 0, 2]);
+    }
+}
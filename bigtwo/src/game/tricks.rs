@@ -0,0 +1,191 @@
+//! The history of a Game as a list of Tricks: who led, who did what in turn, and who won.
+
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::card::set::CardSet;
+use crate::hand::Hand;
+use crate::player::seat::Seat;
+
+/// One Trick: everything from a lead until everybody else has passed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trick {
+    /// The Seat that played the first Hand.
+    pub leader: Seat,
+    /// Every Action taken in this Trick, in order, with the Seat that took it.
+    pub actions: Vec<(Seat, Action)>,
+    /// The Seat that won the Trick, None while it is underway. The Trick the Game ends in is won
+    /// by the winner of the Game.
+    pub winner: Option<Seat>,
+}
+
+impl Trick {
+    /// A Trick led with this Action.
+    fn new(seat: Seat, action: Action) -> Self {
+        Trick {
+            leader: seat,
+            actions: alloc::vec![(seat, action)],
+            winner: None,
+        }
+    }
+
+    /// True once the Trick has a winner.
+    pub fn is_over(&self) -> bool {
+        self.winner.is_some()
+    }
+
+    /// The Hands played in this Trick, in order, with the Seat that played them.
+    pub fn hands(&self) -> impl Iterator<Item = (Seat, &Hand)> + '_ {
+        self.actions
+            .iter()
+            .filter_map(|(seat, action)| action.hand().map(|hand| (*seat, hand)))
+    }
+
+    /// The Seats that passed in this Trick, in order.
+    pub fn passes(&self) -> impl Iterator<Item = Seat> + '_ {
+        self.actions
+            .iter()
+            .filter(|(_, action)| *action == Action::Pass)
+            .map(|(seat, _)| *seat)
+    }
+
+    /// Every Card played in this Trick by this Seat.
+    pub fn cards_played_by(&self, seat: Seat) -> CardSet {
+        self.hands()
+            .filter(|(played_by, _)| *played_by == seat)
+            .map(|(_, hand)| CardSet::from_hand(hand))
+            .fold(CardSet::new(), |cards, hand| cards.union(&hand))
+    }
+}
+
+/// Every Trick of a Game so far, oldest first, see Game::tricks.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrickHistory {
+    tricks: Vec<Trick>,
+}
+
+impl TrickHistory {
+    /// Every Trick, oldest first, the last may still be underway.
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    /// The Trick at this index, counting from 0 for the first Trick of the Game.
+    pub fn trick(&self, index: usize) -> Option<&Trick> {
+        self.tricks.get(index)
+    }
+
+    /// The Trick underway, None between Tricks.
+    pub fn current(&self) -> Option<&Trick> {
+        self.tricks.last().filter(|trick| !trick.is_over())
+    }
+
+    /// Number of Tricks so far, including the one underway.
+    pub fn len(&self) -> usize {
+        self.tricks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tricks.is_empty()
+    }
+
+    /// The Hands played in the Trick at this index, in order, with the Seat that played them.
+    /// Empty if there is no such Trick.
+    pub fn hands_in_trick(&self, index: usize) -> Vec<(Seat, Hand)> {
+        self.trick(index)
+            .map(|trick| trick.hands().map(|(seat, hand)| (seat, *hand)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every Card played by this Seat in the Game so far.
+    pub fn cards_played_by(&self, seat: Seat) -> CardSet {
+        self.tricks.iter().fold(CardSet::new(), |cards, trick| {
+            cards.union(&trick.cards_played_by(seat))
+        })
+    }
+
+    /// Number of Tricks this Seat has won.
+    pub fn tricks_won_by(&self, seat: Seat) -> usize {
+        self.tricks
+            .iter()
+            .filter(|trick| trick.winner == Some(seat))
+            .count()
+    }
+
+    /// Records the Seat taking the Action, which leads a new Trick when there is none underway.
+    pub(crate) fn push(&mut self, seat: Seat, action: Action) {
+        match self.tricks.last_mut() {
+            Some(trick) if !trick.is_over() => trick.actions.push((seat, action)),
+            _ => self.tricks.push(Trick::new(seat, action)),
+        }
+    }
+
+    /// The Trick underway, if any, was won by this Seat.
+    pub(crate) fn end_trick(&mut self, winner: Seat) {
+        if let Some(trick) = self.tricks.last_mut().filter(|trick| !trick.is_over()) {
+            trick.winner = Some(winner);
+        }
+    }
+
+    /// Takes back the last Action recorded, the Trick it was in is underway again.
+    pub(crate) fn pop(&mut self) {
+        if let Some(trick) = self.tricks.last_mut() {
+            trick.winner = None;
+            trick.actions.pop();
+            if trick.actions.is_empty() {
+                self.tricks.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::tests::test_util::{in_order_game, seat};
+
+    #[test]
+    fn test_tricks() {
+        let game = in_order_game(&["3C", "4D", "", "5S", "", "", "6S", ""]);
+        let tricks = game.tricks();
+        assert_eq!(tricks.len(), 2);
+
+        let first = tricks.trick(0).unwrap();
+        assert_eq!(first.leader, seat(0));
+        assert_eq!(first.winner, Some(seat(3)));
+        assert_eq!(
+            first.passes().collect::<Vec<_>>(),
+            [seat(2), seat(0), seat(1)]
+        );
+        assert_eq!(
+            tricks.hands_in_trick(0),
+            [
+                (seat(0), "3C".parse().unwrap()),
+                (seat(1), "4D".parse().unwrap()),
+                (seat(3), "5S".parse().unwrap()),
+            ]
+        );
+
+        // the winner leads the next Trick, which is underway
+        let second = tricks.current().unwrap();
+        assert_eq!(second.leader, seat(3));
+        assert_eq!(tricks.tricks_won_by(seat(3)), 1);
+        assert_eq!(tricks.cards_played_by(seat(3)).to_string(), "5S 6S");
+        assert!(tricks.hands_in_trick(2).is_empty());
+    }
+
+    #[test]
+    fn test_tricks_follow_undo() {
+        let mut game = in_order_game(&["3C", "", "", ""]);
+        assert!(game.tricks().trick(0).unwrap().is_over());
+        game.undo();
+        assert!(game.tricks().current().is_some());
+        game.undo();
+        game.undo();
+        game.undo();
+        assert!(game.tricks().is_empty());
+    }
+}
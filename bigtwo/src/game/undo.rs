@@ -0,0 +1,128 @@
+//! Taking Actions back, and taking them again, e.g. for practice Games and teaching.
+
+use crate::action::Action;
+use crate::game::log::LogEntry;
+use crate::game::observer::GameEvent;
+use crate::game::outcome::StepOutcome;
+use crate::game::Game;
+use crate::player::seat::Seat;
+
+impl Game {
+    /// Takes back the most recent Action, restoring the Game exactly as it was before it, even
+    /// across the end of a Trick. Returns the undone Action and who took it, or None at the start
+    /// of the Game. The log keeps the Action, and records the undo after it. A Game on the clock
+    /// cannot be undone, or a Seat out of time could take its turn again with a fresh clock.
+    pub fn undo(&mut self) -> Option<(Seat, Action)> {
+        if self.timer.is_some() {
+            return None;
+        }
+        let (seat, action) = self.log.actions().last()?;
+        let previous = self.history.pop()?;
+        self.log.push(LogEntry::Undo { seat });
+        if let Action::Play(hand) = action {
+            self.players[seat.index()].return_hand_to_cards(&hand);
+            self.played_hands.pop();
+        }
+        self.state = previous;
+        self.tricks.pop();
+        self.undone.push((seat, action));
+        self.notify(&GameEvent::Undone(seat, action));
+        self.notify(&GameEvent::TurnStarted(seat));
+        Some((seat, action))
+    }
+
+    /// Takes the most recently undone Action again. Returns what happened, or None if nothing
+    /// was undone since the last Action, or the Game is on the clock.
+    pub fn redo(&mut self) -> Option<StepOutcome> {
+        if self.timer.is_some() {
+            return None;
+        }
+        let (seat, action) = self.undone.pop()?;
+        // the Game is exactly as it was when this Action was first taken, so it is still allowed
+        self.advance(seat, action).ok()
+    }
+
+    /// True if there is an Action to undo.
+    pub fn can_undo(&self) -> bool {
+        self.timer.is_none() && !self.history.is_empty()
+    }
+
+    /// True if there is an Action to redo.
+    pub fn can_redo(&self) -> bool {
+        self.timer.is_none() && !self.undone.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::card::Card;
+    use crate::game::state::GameState;
+    use crate::hand::Hand;
+
+    /// Everything undo must restore.
+    fn snapshot(game: &Game) -> (GameState, Vec<Vec<Card>>, Vec<Hand>, usize) {
+        (
+            *game.state(),
+            game.players.iter().map(|p| p.cards.clone()).collect(),
+            game.played_hands.clone(),
+            game.log().actions().count(),
+        )
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut game = Game::with_seed(3);
+        assert!(!game.can_undo());
+        assert!(game.undo().is_none());
+
+        // play until a few Tricks have ended, always taking the weakest Hand, or passing if there
+        // is nothing to beat the last one with
+        let mut snapshots = vec![snapshot(&game)];
+        let mut tricks = 0;
+        while tricks < 3 {
+            let seat = game.current_seat();
+            let actions = game.legal_actions(seat);
+            let action = match actions[..] {
+                [Action::Pass, weakest, ..] => weakest,
+                _ => actions[0],
+            };
+            let outcome = game.apply(seat, action).unwrap();
+            tricks += usize::from(outcome.trick_winner.is_some());
+            snapshots.push(snapshot(&game));
+        }
+
+        // undo everything, one Action at a time
+        for expected in snapshots.iter().rev().skip(1) {
+            assert!(game.undo().is_some());
+            assert_eq!(&snapshot(&game), expected);
+        }
+        assert!(game.undo().is_none());
+        // nothing is removed from the log, every undo is added to it
+        let actions = snapshots.len() - 1;
+        assert_eq!(game.log().entries().len(), 1 + 2 * actions);
+        assert_eq!(Game::replay(game.log()).unwrap().state(), game.state());
+
+        // and redo it all again
+        for expected in snapshots.iter().skip(1) {
+            assert!(game.redo().is_some());
+            assert_eq!(&snapshot(&game), expected);
+        }
+        assert!(game.redo().is_none());
+    }
+
+    #[test]
+    fn test_apply_clears_redo() {
+        let mut game = Game::with_seed(3);
+        let seat = game.current_seat();
+        let actions = game.legal_actions(seat);
+        game.apply(seat, actions[0]).unwrap();
+        game.undo();
+        assert!(game.can_redo());
+
+        game.apply(seat, actions[1]).unwrap();
+        assert!(!game.can_redo());
+        assert!(game.redo().is_none());
+    }
+}
@@ -0,0 +1,119 @@
+//! What one Seat is allowed to know about a Game, e.g. to send to a client or to hand to a bot.
+
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::card::set::CardSet;
+use crate::game::rules::Rules;
+use crate::game::{Game, NUM_PLAYERS};
+use crate::hand::Hand;
+use crate::player::seat::{Seat, SeatSet};
+
+/// A Game as seen from one Seat: its own Cards, but only how many Cards everyone else holds.
+/// Everything else in it is public, i.e. seen by everyone at the table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerView {
+    /// The Seat this is the view of.
+    pub seat: Seat,
+
+    /// The Cards held by this Seat.
+    pub cards: CardSet,
+
+    /// How many Cards each Seat holds, at the index of that Seat.
+    pub card_counts: [usize; NUM_PLAYERS],
+
+    /// Every Action taken so far, oldest first, with the Seat that took it.
+    pub history: Vec<(Seat, Action)>,
+
+    /// The Hand to beat and who played it, None until the first Hand of the Game.
+    pub last_play: Option<(Seat, Hand)>,
+
+    /// The Seats that have passed so far this Trick.
+    pub passed: SeatSet,
+
+    /// The Seat whose turn it is.
+    pub current_seat: Seat,
+
+    /// True when the current Seat may lead anything.
+    pub is_start_trick: bool,
+
+    /// The Seats that have forfeited the Game.
+    pub forfeited: SeatSet,
+
+    /// The house Rules the Game is played by.
+    pub rules: Rules,
+}
+
+impl PlayerView {
+    /// True if it is this Seat's turn.
+    pub fn is_my_turn(&self) -> bool {
+        self.seat == self.current_seat
+    }
+
+    /// The Hand to beat, None until the first Hand of the Game.
+    pub fn last_hand(&self) -> Option<&Hand> {
+        self.last_play.as_ref().map(|(_, hand)| hand)
+    }
+}
+
+impl Game {
+    /// What the Player at this Seat is allowed to know about the Game.
+    pub fn view(&self, seat: Seat) -> PlayerView {
+        let state = self.state();
+        PlayerView {
+            seat,
+            cards: *state.hand(seat),
+            card_counts: state.hands().map(|hand| hand.len()),
+            history: self.log().actions().collect(),
+            last_play: state.last_play(),
+            passed: state.passed(),
+            current_seat: state.current_seat(),
+            is_start_trick: state.is_start_trick(),
+            forfeited: state.forfeited(),
+            rules: *state.rules(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_view() {
+        let mut game = Game::with_seed(11);
+        let first = game.current_seat();
+        let action = game.legal_actions(first)[0];
+        game.apply(first, action).unwrap();
+
+        let seat = game.current_seat();
+        let view = game.view(seat);
+        assert!(view.is_my_turn());
+        assert_eq!(view.cards.to_vec(), game.player(seat).cards);
+        assert_eq!(
+            view.card_counts[first.index()],
+            13 - action.hand().unwrap().cards().len()
+        );
+        assert_eq!(view.history, [(first, action)]);
+        assert_eq!(view.last_hand(), action.hand());
+        assert!(!game.view(first).is_my_turn());
+    }
+
+    #[test]
+    fn test_view_hides_other_cards() {
+        let game = Game::with_seed(11);
+        let seat = Seat::new(0).unwrap();
+        let json = serde_json::to_string(&game.view(seat)).unwrap();
+        for other in Seat::all().into_iter().filter(|other| *other != seat) {
+            for card in &game.player(other).cards {
+                let card_json = serde_json::to_string(card).unwrap();
+                assert!(!json.contains(&card_json), "{card} leaked");
+            }
+        }
+        let view: PlayerView = serde_json::from_str(&json).unwrap();
+        assert_eq!(view, game.view(seat));
+    }
+}
@@ -0,0 +1,120 @@
+//! Zobrist hashing of GameStates: every feature of a position has a fixed random key, and the
+//! hash of a position is the xor of the keys of its features. Xor is its own inverse and does
+//! not care about order, so the hash can be updated move by move, and positions reached by
+//! different move orders hash identically.
+
+use crate::card::Card;
+use crate::game::state::GameState;
+use crate::game::NUM_PLAYERS;
+use crate::hand::Hand;
+use crate::player::seat::Seat;
+
+/// Number of distinct Cards, each has a key per Seat holding it and one for the Hand to beat.
+const NUM_CARDS: usize = 52;
+
+/// Keys for a Card being held by a Seat.
+const HELD: usize = 0;
+/// Keys for a Card being part of the Hand to beat.
+const LAST_CARD: usize = HELD + NUM_PLAYERS * NUM_CARDS;
+/// Keys for the Seat that played the Hand to beat.
+const LAST_SEAT: usize = LAST_CARD + NUM_CARDS;
+/// Keys for a Seat having passed this Trick.
+const PASSED: usize = LAST_SEAT + NUM_PLAYERS;
+/// Keys for the Seat whose turn it is.
+const TURN: usize = PASSED + NUM_PLAYERS;
+/// Key for being at the start of a Trick.
+const START_TRICK: usize = TURN + NUM_PLAYERS;
+/// Keys for a Seat having forfeited the Game.
+const FORFEITED: usize = START_TRICK + 1;
+const NUM_KEYS: usize = FORFEITED + NUM_PLAYERS;
+
+/// Fixed so that hashes are the same across runs, builds and machines.
+const SEED: u64 = 0xB16_7700_2C0D_E5EE;
+
+/// Generated at compile time with splitmix64, which is plenty random for Zobrist keys.
+const KEYS: [u64; NUM_KEYS] = {
+    let mut keys = [0; NUM_KEYS];
+    let mut state = SEED;
+    let mut i = 0;
+    while i < NUM_KEYS {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        keys[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    keys
+};
+
+/// The key for this Card being held by this Seat.
+pub fn held(seat: Seat, card: &Card) -> u64 {
+    KEYS[HELD + seat.index() * NUM_CARDS + usize::from(card.index())]
+}
+
+/// The key for this Hand being the Hand to beat, played by this Seat.
+pub fn last_play(seat: Seat, hand: &Hand) -> u64 {
+    hand.cards()
+        .fold(KEYS[LAST_SEAT + seat.index()], |key, card| {
+            key ^ KEYS[LAST_CARD + usize::from(card.index())]
+        })
+}
+
+/// The key for this Seat having passed this Trick.
+pub fn passed(seat: Seat) -> u64 {
+    KEYS[PASSED + seat.index()]
+}
+
+/// The key for it being this Seat's turn.
+pub fn turn(seat: Seat) -> u64 {
+    KEYS[TURN + seat.index()]
+}
+
+/// The key for being at the start of a Trick.
+pub fn start_trick() -> u64 {
+    KEYS[START_TRICK]
+}
+
+/// The key for this Seat having forfeited the Game.
+pub fn forfeited(seat: Seat) -> u64 {
+    KEYS[FORFEITED + seat.index()]
+}
+
+/// Hashes a GameState from scratch. GameState keeps its hash up to date incrementally, this is
+/// what it must always be equal to.
+pub fn hash(state: &GameState) -> u64 {
+    let mut hash = turn(state.current_seat());
+    for seat in Seat::all() {
+        for card in state.hand(seat).iter() {
+            hash ^= held(seat, &card);
+        }
+    }
+    if let Some((seat, hand)) = state.last_play() {
+        hash ^= last_play(seat, &hand);
+    }
+    for seat in state.passed().iter() {
+        hash ^= passed(seat);
+    }
+    if state.is_start_trick() {
+        hash ^= start_trick();
+    }
+    for seat in state.forfeited().iter() {
+        hash ^= forfeited(seat);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_keys_are_distinct() {
+        let mut keys = KEYS.to_vec();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), NUM_KEYS);
+        assert!(!keys.contains(&0));
+    }
+}
@@ -2,20 +2,28 @@
 //! Cannot be used to represent an unrecognized / nonsensical combination.
 
 pub mod iter;
+pub mod kind;
 pub mod order;
 pub mod try_from;
 
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
 use core::fmt;
 
+use crate::card::notation::{Notation, Render};
 use crate::card::Card;
 
+use serde::{Deserialize, Serialize};
+
 /// Represents any one of the allowed combinations of cards (known as a "Hand").
 /// Cannot be used to represent an unrecognized / nonsensical combination.
-/// TODO: Fivers: Straight, Flush, FullHouse, FourPlusKicker, StraightFlush
-#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Passing is not a Hand, see Action.
+/// Serializes as its Display string, e.g. "KS KH".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum Hand {
-    /// No Hand, No Cards
-    Pass,
     /// aka Singles, Highs, Loners, Solos
     Lone(Card),
     /// aka Dubs, Dual, Two-of-a-Kind
@@ -40,8 +48,19 @@ impl fmt::Display for Hand {
         for card in self.cards() {
             out.push(card.to_string());
         }
-        let out = out.join(" ");
-        let out = out.trim();
-        write!(f, "{}", out)
+        write!(f, "{}", out.join(" "))
+    }
+}
+
+impl From<Hand> for String {
+    fn from(hand: Hand) -> Self {
+        hand.to_string()
+    }
+}
+
+impl Render for Hand {
+    fn render(&self, notation: Notation) -> String {
+        let cards: Vec<Card> = self.cards().copied().collect();
+        cards.render(notation)
     }
 }
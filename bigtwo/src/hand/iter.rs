@@ -3,7 +3,7 @@
 use crate::card::Card;
 use crate::hand::Hand;
 
-use std::ops::Index;
+use core::ops::Index;
 
 impl Index<usize> for Hand {
     type Output = Card;
@@ -66,20 +66,22 @@ impl<'a> Iterator for HandIterator<'a> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        match self.hand {
-            Hand::Pass => (0, Some(0)),
-            Hand::Lone(..) => (1 - self.index, Some(1 - self.index)),
-            Hand::Pair(..) => (2 - self.index, Some(2 - self.index)),
-            Hand::Trips(..) => (3 - self.index, Some(3 - self.index)),
-            _ => (5 - self.index, Some(5 - self.index)),
-        }
+        let len: usize = match self.hand {
+            Hand::Lone(..) => 1,
+            Hand::Pair(..) => 2,
+            Hand::Trips(..) => 3,
+            _ => 5,
+        };
+        // saturate, since next() may keep being called after the last Card
+        let remaining = len.saturating_sub(self.index);
+        (remaining, Some(remaining))
     }
 }
 
 impl<'a> ExactSizeIterator for HandIterator<'a> {}
 
 impl Hand {
-    pub fn cards(&self) -> HandIterator {
+    pub fn cards(&self) -> HandIterator<'_> {
         HandIterator::new(self)
     }
 }
@@ -98,4 +100,14 @@ mod tests {
             assert_eq!(*cards[i], hand[i]);
         }
     }
+
+    #[test]
+    fn test_iterator_past_the_end() {
+        let hand: Hand = "2S".parse().unwrap();
+        let mut cards = hand.cards();
+        assert!(cards.next().is_some());
+        assert!(cards.next().is_none());
+        assert!(cards.next().is_none());
+        assert_eq!(cards.len(), 0);
+    }
 }
@@ -0,0 +1,215 @@
+//! Classifies a Hand into its HandKind, and computes a strength key for comparing two Hands of
+//! the same HandKind with a single integer comparison.
+
+use alloc::format;
+use alloc::string::String;
+
+use core::fmt;
+
+use crate::card::Card;
+use crate::hand::Hand;
+
+/// The kind of combination a Hand is, ordered so that among the Fivers a higher HandKind always
+/// beats a lower HandKind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HandKind {
+    Lone,
+    Pair,
+    Trips,
+    Straight,
+    Flush,
+    FullHouse,
+    FourPlusKick,
+    StraightFlush,
+}
+
+impl HandKind {
+    /// True for the five card Hands, which may be played atop each other regardless of HandKind.
+    pub fn is_fiver(&self) -> bool {
+        matches!(
+            self,
+            HandKind::Straight
+                | HandKind::Flush
+                | HandKind::FullHouse
+                | HandKind::FourPlusKick
+                | HandKind::StraightFlush
+        )
+    }
+}
+
+impl fmt::Display for HandKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandKind::Lone => write!(f, "Lone"),
+            HandKind::Pair => write!(f, "Pair"),
+            HandKind::Trips => write!(f, "Trips"),
+            HandKind::Straight => write!(f, "Straight"),
+            HandKind::Flush => write!(f, "Flush"),
+            HandKind::FullHouse => write!(f, "Full House"),
+            HandKind::FourPlusKick => write!(f, "Four Plus Kick"),
+            HandKind::StraightFlush => write!(f, "Straight Flush"),
+        }
+    }
+}
+
+/// Number of distinct Cards, used as the base when packing several Cards into one strength key.
+const CARD_KEY_BASE: u32 = 52;
+
+/// Packs Cards (most significant first) into one key, preserving their lexicographic order.
+fn pack(cards: &[&Card]) -> u32 {
+    cards
+        .iter()
+        .fold(0, |key, card| key * CARD_KEY_BASE + u32::from(card.index()))
+}
+
+impl Hand {
+    /// Identify which kind of combination this Hand is.
+    pub fn kind(&self) -> HandKind {
+        match self {
+            Hand::Lone(..) => HandKind::Lone,
+            Hand::Pair(..) => HandKind::Pair,
+            Hand::Trips(..) => HandKind::Trips,
+            Hand::Straight(..) => HandKind::Straight,
+            Hand::Flush(..) => HandKind::Flush,
+            Hand::FullHouse(..) => HandKind::FullHouse,
+            Hand::FourPlusKick(..) => HandKind::FourPlusKick,
+            Hand::StraightFlush(..) => HandKind::StraightFlush,
+        }
+    }
+
+    /// A key that orders Hands of the same HandKind, i.e. for two Hands `a` and `b` of the same
+    /// HandKind, `a` beats `b` exactly when `a.strength() > b.strength()`.
+    /// Comparing the strength of Hands of different HandKinds is meaningless.
+    pub fn strength(&self) -> u32 {
+        match self {
+            Hand::Lone(a) => pack(&[a]),
+            Hand::Pair(a, b) => pack(&[a, b]),
+            Hand::Trips(a, b, c) => pack(&[a, b, c]),
+            Hand::Straight(a, b, c, d, e)
+            | Hand::Flush(a, b, c, d, e)
+            | Hand::StraightFlush(a, b, c, d, e) => pack(&[a, b, c, d, e]),
+            // FullHouse is either AAABB or AABBB, the Trip decides, then the Pair.
+            Hand::FullHouse(a, b, c, d, e) => {
+                if a.rank == c.rank {
+                    pack(&[a, b, c, d, e])
+                } else {
+                    pack(&[c, d, e, a, b])
+                }
+            }
+            // FourPlusKick is either AAAAB or ABBBB, the Quad decides, then the Kicker.
+            Hand::FourPlusKick(a, b, _, _, e) => {
+                if a.rank == b.rank {
+                    pack(&[a, e])
+                } else {
+                    pack(&[b, a])
+                }
+            }
+        }
+    }
+
+    /// A human readable description of this Hand, e.g. "Full House, Kings over Fours".
+    pub fn label(&self) -> String {
+        match self {
+            Hand::Lone(a) => format!("{} {}", self.kind(), a.rank.name()),
+            Hand::Pair(a, _) | Hand::Trips(a, _, _) => {
+                format!("{} of {}", self.kind(), a.rank.plural())
+            }
+            Hand::Straight(a, ..) | Hand::Flush(a, ..) | Hand::StraightFlush(a, ..) => {
+                format!("{}, {} high", self.kind(), a.rank.name())
+            }
+            Hand::FullHouse(a, _, c, _, e) => {
+                let (trip, pair) = if a.rank == c.rank { (a, e) } else { (e, a) };
+                format!(
+                    "{}, {} over {}",
+                    self.kind(),
+                    trip.rank.plural(),
+                    pair.rank.plural()
+                )
+            }
+            Hand::FourPlusKick(a, b, _, _, e) => {
+                let (quad, kick) = if a.rank == b.rank { (a, e) } else { (b, a) };
+                format!(
+                    "{}, {} with a {}",
+                    self.kind(),
+                    quad.rank.plural(),
+                    kick.rank.name()
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_kind() {
+        let kinds = [
+            ("2S", HandKind::Lone),
+            ("3D 3C", HandKind::Pair),
+            ("KS KH KC", HandKind::Trips),
+            ("8S 7D 6S 5C 4C", HandKind::Straight),
+            ("AD TD 5D 4D 3D", HandKind::Flush),
+            ("2S 2D 7S 7D 7C", HandKind::FullHouse),
+            ("2S 5S 5H 5D 5C", HandKind::FourPlusKick),
+            ("7S 6S 5S 4S 3S", HandKind::StraightFlush),
+        ];
+        for (hand, kind) in kinds {
+            let hand: Hand = hand.parse().unwrap();
+            assert_eq!(hand.kind(), kind);
+            assert_eq!(hand.kind().is_fiver(), hand.cards().len() == 5);
+        }
+    }
+
+    #[test]
+    fn test_strength() {
+        // each successive hand is stronger than the previous hand in the list, within a kind
+        let ladders = [
+            ["3C", "3S", "4C", "2S"],
+            ["3D 3C", "3S 3C", "3S 3H", "2D 2C"],
+            [
+                "8S 8D 8C 4H 4D",
+                "8S 8H 8C 3S 3D",
+                "8S 8H 8D 3H 3C",
+                "2S 2D 9S 9D 9C",
+            ],
+            [
+                "AS AH AD AC 3C",
+                "AS AH AD AC KC",
+                "2C AS AH AD AC",
+                "2S 2H 2D 2C 3C",
+            ],
+        ];
+        for ladder in ladders {
+            for i in 1..ladder.len() {
+                let bigger: Hand = ladder[i].parse().unwrap();
+                let smaller: Hand = ladder[i - 1].parse().unwrap();
+                assert!(
+                    bigger.strength() > smaller.strength(),
+                    "{bigger} > {smaller}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_label() {
+        let labels = [
+            ("2S", "Lone Two"),
+            ("3D 3C", "Pair of Threes"),
+            ("KS KH KC", "Trips of Kings"),
+            ("8S 7D 6S 5C 4C", "Straight, Eight high"),
+            ("AD TD 5D 4D 3D", "Flush, Ace high"),
+            ("KS KD KC 4D 4C", "Full House, Kings over Fours"),
+            ("2S 2D 7S 7D 7C", "Full House, Sevens over Twos"),
+            ("2S 5S 5H 5D 5C", "Four Plus Kick, Fives with a Two"),
+            ("7S 6S 5S 4S 3S", "Straight Flush, Seven high"),
+        ];
+        for (hand, label) in labels {
+            let hand: Hand = hand.parse().unwrap();
+            assert_eq!(hand.label(), label);
+        }
+    }
+}
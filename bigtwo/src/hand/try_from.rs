@@ -1,14 +1,18 @@
 //! implements FromString for Hand, as well as helper function try_from_cards for making
 //! valid hands out of Vec<Card> and &[Card]
 
-use std::collections::BTreeSet;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use alloc::collections::BTreeSet;
+use core::str::FromStr;
 
 use crate::card::Card;
 use crate::card::ParseCardError;
 use crate::hand::Hand;
 
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
 
 /// Represents the possible ways that a string can fail to parse into a reasonable Hand.
 #[derive(Debug)]
@@ -30,7 +34,7 @@ impl From<ParseCardError> for ParseHandError {
 }
 
 impl Display for ParseHandError {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         match self {
             Self::BadCard(_) => write!(f, "{:?}", self),
             Self::DuplicateCard => write!(f, "{:?}", self),
@@ -47,10 +51,12 @@ pub enum InvalidHandError {
     UnmatchedPair,
     /// Three cards, at least one is a different Rank
     UnmatchedTrips,
-    /// Incorrect number of cards (0, 4, 6+)
+    /// Incorrect number of cards (0, 4, 6+), passing is an Action rather than a Hand
     WrongQuantity,
     /// Not a valid Five Card Hand
     NotAFiveCardHand,
+    /// Playing with a single deck, the same Card cannot appear twice in one Hand.
+    DuplicateCard,
 }
 
 impl From<InvalidHandError> for ParseHandError {
@@ -63,7 +69,6 @@ impl Hand {
     /// Given a slice of Cards, either return a Hand, or an Error
     pub fn try_from_cards(cards: &[Card]) -> Result<Hand, ParseHandError> {
         match cards {
-            [] => Ok(Hand::Pass),
             [a] => Ok(Hand::Lone(*a)),
             [a, b] => Ok(Hand::try_pair(*a, *b)?),
             [a, b, c] => Ok(Hand::try_trips(*a, *b, *c)?),
@@ -72,9 +77,9 @@ impl Hand {
         }
     }
 
-    /// Given two cards, return a Pair or an Error
+    /// Given two cards in any order, return a Pair or an Error
     pub fn try_pair(first: Card, second: Card) -> Result<Hand, InvalidHandError> {
-        assert!(second < first);
+        let [first, second] = Hand::sort_descending([first, second])?;
         if Hand::check_pair(&first, &second) {
             Ok(Hand::Pair(first, second))
         } else {
@@ -82,15 +87,27 @@ impl Hand {
         }
     }
 
+    /// Every Hand keeps its Cards in descending order, so that the derived Ord and the strength
+    /// key work. Sorts the given Cards that way, or returns an Error if any Card is repeated.
+    fn sort_descending<const N: usize>(
+        mut cards: [Card; N],
+    ) -> Result<[Card; N], InvalidHandError> {
+        cards.sort_by(|a, b| b.cmp(a));
+        if cards.windows(2).any(|pair| pair[0] == pair[1]) {
+            Err(InvalidHandError::DuplicateCard)
+        } else {
+            Ok(cards)
+        }
+    }
+
     /// Returns true if first and second make a valid Pair
     fn check_pair(first: &Card, second: &Card) -> bool {
         first.rank == second.rank
     }
 
-    /// Given three cards, return a Trip or an Error
+    /// Given three cards in any order, return a Trip or an Error
     pub fn try_trips(first: Card, second: Card, third: Card) -> Result<Hand, InvalidHandError> {
-        assert!(third < second);
-        assert!(second < first);
+        let [first, second, third] = Hand::sort_descending([first, second, third])?;
         if Hand::check_trips(&first, &second, &third) {
             Ok(Hand::Trips(first, second, third))
         } else {
@@ -103,7 +120,7 @@ impl Hand {
         first.rank == second.rank && second.rank == third.rank
     }
 
-    /// Given five cards, return either a valid Hand or an error
+    /// Given five cards in any order, return either a valid Hand or an error
     pub fn try_fiver(
         first: Card,
         second: Card,
@@ -111,10 +128,8 @@ impl Hand {
         fourth: Card,
         fifth: Card,
     ) -> Result<Hand, InvalidHandError> {
-        assert!(fifth < fourth);
-        assert!(fourth < third);
-        assert!(third < second);
-        assert!(second < first);
+        let [first, second, third, fourth, fifth] =
+            Hand::sort_descending([first, second, third, fourth, fifth])?;
 
         let is_straight = Hand::check_straight(&first, &second, &third, &fourth, &fifth);
         let is_flush = Hand::check_flush(&first, &second, &third, &fourth, &fifth);
@@ -200,6 +215,13 @@ impl Hand {
     }
 }
 
+impl TryFrom<String> for Hand {
+    type Error = ParseHandError;
+    fn try_from(hand_str: String) -> Result<Self, Self::Error> {
+        hand_str.parse()
+    }
+}
+
 impl FromStr for Hand {
     type Err = ParseHandError;
 
@@ -207,7 +229,7 @@ impl FromStr for Hand {
         let mut hand_str: String = hand_str.trim().to_string();
         hand_str.retain(|c| !r#"(),".;:'|"#.contains(c));
         if hand_str.is_empty() {
-            return Ok(Hand::Pass);
+            return Err(InvalidHandError::WrongQuantity.into());
         }
         let maybe_cards = hand_str.split(' ').collect::<Vec<&str>>();
         let mut cards: Vec<Card> = vec![];
@@ -234,6 +256,12 @@ mod tests {
         let hand = "7D 5C 4C 3".to_string().parse::<Hand>();
         assert!(matches!(hand, Err(ParseHandError::BadCard(_))));
 
+        let hand = "".to_string().parse::<Hand>();
+        assert!(matches!(
+            hand,
+            Err(ParseHandError::InvalidHand(InvalidHandError::WrongQuantity))
+        ));
+
         let hand = "7D 5C 4C 3C".to_string().parse::<Hand>();
         assert!(matches!(
             hand,
@@ -269,10 +297,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_try_from_unsorted_cards() {
+        let [a, b, c, d, e]: [Card; 5] = ["3C", "5D", "4C", "7S", "6H"].map(|c| c.parse().unwrap());
+
+        let hand = Hand::try_pair(a, "3S".parse().unwrap()).unwrap();
+        assert_eq!(hand.to_string(), "3S 3C");
+
+        let hand = Hand::try_fiver(a, b, c, d, e).unwrap();
+        assert_eq!(hand.to_string(), "7S 6H 5D 4C 3C");
+
+        let hand = Hand::try_pair(a, a);
+        assert!(matches!(hand, Err(InvalidHandError::DuplicateCard)));
+
+        let hand = Hand::try_trips(a, b, a);
+        assert!(matches!(hand, Err(InvalidHandError::DuplicateCard)));
+    }
+
     #[test]
     fn test_good_hand_to_from_string() {
         let good_hands = [
-            "",
             "2S",
             "3D 3C",
             "KS KH KC",
@@ -287,7 +331,7 @@ mod tests {
             println!("AAAAAAA: {expected_hand}");
             let hand = expected_hand.to_string().parse::<Hand>();
             println!("debug hand {hand:?}");
-            assert!(matches!(hand, Ok(_)));
+            assert!(hand.is_ok());
             let result_hand = hand.unwrap();
             println!("result hand {result_hand:?}");
             let result_hand = result_hand.to_string();
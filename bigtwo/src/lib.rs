@@ -1,4 +1,10 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod action;
 pub mod card;
+pub mod dealer;
 pub mod deck;
 pub mod game;
 pub mod hand;
@@ -1,38 +1,99 @@
 //! Represents a player in the game, could be AI or User.
+pub mod bot;
+pub mod controller;
 pub mod get_ai_input;
+pub mod seat;
+pub mod status;
 
-use std::collections::BTreeSet;
-use std::fmt::Display;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 
+use alloc::collections::BTreeSet;
+use core::fmt::Display;
+
+use crate::card::notation::{Notation, Render};
+use crate::player::controller::Controller;
+use crate::player::seat::{PlayerId, Seat};
+use crate::player::status::SeatStatus;
 use crate::{card::cards_to_string, card::Card, hand::Hand};
 
 use serde::{Deserialize, Serialize};
 
+/// Represents the ways a Player's cards cannot be changed as requested.
+#[derive(Debug)]
+pub enum PlayerError {
+    /// The Player does not have (all of) the Cards of this Hand.
+    MissingCards,
+}
+
+impl Display for PlayerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::MissingCards => write!(f, "these cards are not in the players hand"),
+        }
+    }
+}
+
 /// Represents a player in the game.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Player {
+    /// Who this is, does not change even if they move Seats or change their name.
+    pub id: PlayerId,
+    /// What to call this Player in logs, scoreboards, etc.
+    pub name: String,
+    /// Where this Player sits at the table.
+    pub seat: Seat,
+    /// Who (or what) decides this Player's plays.
+    pub controller: Controller,
+    /// Whether this Player is still taking part, see Game::take_over and friends.
+    #[serde(default)]
+    pub status: SeatStatus,
     pub cards: Vec<Card>,
 }
 
 impl Display for Player {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{}", cards_to_string(&self.cards))
     }
 }
 
+impl Render for Player {
+    fn render(&self, notation: Notation) -> String {
+        self.cards.render(notation)
+    }
+}
+
 impl Player {
+    /// A Player with no cards (yet).
+    pub fn new(id: PlayerId, name: String, seat: Seat, controller: Controller) -> Player {
+        Player {
+            id,
+            name,
+            seat,
+            controller,
+            status: SeatStatus::Present,
+            cards: vec![],
+        }
+    }
+
     /// Used by the caller / game logic to take a Player's cards (ostensibly after the Player has
     /// played them legally).
-    pub fn remove_hand_from_cards(&mut self, hand: &Hand) {
-        assert!(self.has_cards(hand));
-        for to_remove in hand.cards() {
-            let index = self
-                .cards
-                .iter()
-                .position(|card| *card == *to_remove)
-                .unwrap();
-            self.cards.remove(index);
+    /// Leaves the Player's cards untouched and returns an Error if any of them are missing.
+    pub fn remove_hand_from_cards(&mut self, hand: &Hand) -> Result<(), PlayerError> {
+        if !self.has_cards(hand) {
+            return Err(PlayerError::MissingCards);
         }
+        self.cards
+            .retain(|card| !hand.cards().any(|to_remove| to_remove == card));
+        Ok(())
+    }
+
+    /// Gives the Player back the cards of a Hand they played, e.g. when it is undone.
+    /// Keeps the Player's cards sorted.
+    pub fn return_hand_to_cards(&mut self, hand: &Hand) {
+        self.cards.extend(hand.cards());
+        self.cards.sort();
     }
 
     /// Used to make sure the Player actually has the cards they tried to play.
@@ -51,8 +112,10 @@ mod tests {
     #[test]
     fn test_has_cards() {
         let cards = vec_card_from_str("3C 3S 4H 4D 4S");
-        let mut player = Player::default();
-        player.cards = cards;
+        let player = Player {
+            cards,
+            ..Default::default()
+        };
 
         let hand: Hand = "3C".parse().unwrap();
         assert!(player.has_cards(&hand));
@@ -72,12 +135,23 @@ mod tests {
 
     #[test]
     fn test_remove_cards_from_hand() {
-        let mut player = Player::default();
-        player.cards = vec_card_from_str("3D 3S 5S 6S");
-        player.remove_hand_from_cards(&"3S 3D".parse().unwrap());
+        let mut player = Player {
+            cards: vec_card_from_str("3D 3S 5S 6S"),
+            ..Default::default()
+        };
+        let res = player.remove_hand_from_cards(&"3S 3D".parse().unwrap());
+        assert!(res.is_ok());
         assert!(!player.cards.contains(&"3S".parse().unwrap()));
         assert!(!player.cards.contains(&"3D".parse().unwrap()));
         assert!(player.cards.contains(&"5S".parse().unwrap()));
         assert!(player.cards.contains(&"6S".parse().unwrap()));
+
+        // cannot remove cards that are not there, and nothing is removed
+        let res = player.remove_hand_from_cards(&"6S 6D".parse().unwrap());
+        assert!(matches!(res, Err(PlayerError::MissingCards)));
+        assert_eq!(player.cards.len(), 2);
+
+        player.return_hand_to_cards(&"3S 3D".parse().unwrap());
+        assert_eq!(player.cards, vec_card_from_str("3D 3S 5S 6S"));
     }
 }
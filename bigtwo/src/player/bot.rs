@@ -0,0 +1,53 @@
+//! Bots decide their Actions from their Seat's PlayerView alone, so they cannot see (or play
+//! with) anybody else's Cards.
+
+use alloc::vec::Vec;
+
+use crate::action::Action;
+use crate::card::{Card, THREE_OF_CLUBS};
+use crate::game::view::PlayerView;
+use crate::player::controller::BotKind;
+use crate::player::get_ai_input::*;
+
+/// Anything that can choose an Action for a Seat, given only what that Seat can see.
+pub trait Bot {
+    fn choose_action(&mut self, view: &PlayerView) -> Result<Action, AiInputError>;
+}
+
+impl Bot for BotKind {
+    fn choose_action(&mut self, view: &PlayerView) -> Result<Action, AiInputError> {
+        match self {
+            BotKind::Basic => basic(view),
+        }
+    }
+}
+
+/// Opens with the Three of Clubs, leads its lowest Lone, and follows with the lowest Lone that
+/// beats the last one, passing on anything else.
+fn basic(view: &PlayerView) -> Result<Action, AiInputError> {
+    let cards: Vec<Card> = view.cards.to_vec();
+    match view.last_hand() {
+        Some(last) if !view.is_start_trick => Ok(play_smallest_single_or_pass(last, &cards)),
+        None if view.cards.contains(&THREE_OF_CLUBS) => play_three_of_clubs(&cards),
+        // a leader without the Three of Clubs may open with anything
+        _ => start_trick_with_lowest_single(&cards),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::game::Game;
+
+    #[test]
+    fn test_basic_bot_plays_legally() {
+        let mut game = Game::with_seed(5);
+        while game.is_on() {
+            let seat = game.current_seat();
+            let action = BotKind::Basic.choose_action(&game.view(seat)).unwrap();
+            assert!(game.legal_actions(seat).contains(&action), "{action}");
+            game.apply(seat, action).unwrap();
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Describes who (or what) decides the plays for a Player.
+
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// The kinds of built-in bot that can control a Player.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BotKind {
+    /// Opens with the Three of Clubs, and otherwise plays the lowest Lone it can, see bot.
+    #[default]
+    Basic,
+}
+
+/// Who (or what) decides the plays for a Player.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Controller {
+    /// A person playing on this machine.
+    #[default]
+    Human,
+    /// One of the built-in bots.
+    Bot(BotKind),
+    /// A person or program playing from somewhere else, e.g. over the network.
+    Remote,
+}
+
+impl Display for Controller {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Controller::Human => write!(f, "Human"),
+            Controller::Bot(kind) => write!(f, "Bot ({:?})", kind),
+            Controller::Remote => write!(f, "Remote"),
+        }
+    }
+}
@@ -1,36 +1,63 @@
+use crate::action::Action;
+use crate::card::rank::Rank;
 use crate::card::{Card, THREE_OF_CLUBS};
 use crate::hand::Hand;
 
-pub fn play_three_of_clubs(cards: &Vec<Card>) -> Hand {
-    assert_eq!(cards[0], THREE_OF_CLUBS);
-
-    match cards[..] {
-        [a, b, c, ..] => {
-            if let Ok(trips) = Hand::try_trips(c, b, a) {
-                trips
-            } else if let Ok(pair) = Hand::try_pair(b, a) {
-                pair
-            } else {
-                Hand::Lone(a)
-            }
+use core::fmt::Display;
+
+/// Represents the situations the AI cannot come up with a Hand for.
+#[derive(Debug)]
+pub enum AiInputError {
+    /// Asked to open the game, but does not have the Three of Clubs.
+    NoThreeOfClubs,
+    /// Asked to start a trick, but has no cards left.
+    NoCards,
+}
+
+impl Display for AiInputError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::NoThreeOfClubs => write!(f, "does not have the Three of Clubs"),
+            Self::NoCards => write!(f, "has no cards left"),
         }
-        _ => unreachable!(),
     }
 }
 
-pub fn play_smallest_single_or_pass(hand: &Hand, cards: &Vec<Card>) -> Hand {
+/// Opens the game with the Three of Clubs, along with as many other Threes as possible.
+pub fn play_three_of_clubs(cards: &[Card]) -> Result<Action, AiInputError> {
+    if !cards.contains(&THREE_OF_CLUBS) {
+        return Err(AiInputError::NoThreeOfClubs);
+    }
+    let mut other_threes = cards
+        .iter()
+        .filter(|card| card.rank == Rank::Three && **card != THREE_OF_CLUBS);
+
+    // the other Threes are distinct from the Three of Clubs, so these cannot fail
+    let hand = match (other_threes.next(), other_threes.next()) {
+        (Some(b), Some(c)) => Hand::try_trips(THREE_OF_CLUBS, *b, *c),
+        (Some(b), None) => Hand::try_pair(THREE_OF_CLUBS, *b),
+        _ => Ok(Hand::Lone(THREE_OF_CLUBS)),
+    };
+    Ok(Action::Play(hand.unwrap_or(Hand::Lone(THREE_OF_CLUBS))))
+}
+
+pub fn play_smallest_single_or_pass(hand: &Hand, cards: &[Card]) -> Action {
     if let Hand::Lone(c) = hand {
         for card in cards {
             if card > c {
-                return Hand::Lone(*card);
+                return Action::Play(Hand::Lone(*card));
             }
         }
     }
-    Hand::Pass
+    Action::Pass
 }
 
-pub fn start_trick_with_lowest_single(cards: &Vec<Card>) -> Hand {
-    Hand::Lone(cards[0])
+pub fn start_trick_with_lowest_single(cards: &[Card]) -> Result<Action, AiInputError> {
+    cards
+        .iter()
+        .min()
+        .map(|card| Action::Play(Hand::Lone(*card)))
+        .ok_or(AiInputError::NoCards)
 }
 
 #[cfg(test)]
@@ -47,35 +74,49 @@ mod tests {
         let hand = play_smallest_single_or_pass(&hand_to_beat, &player_cards);
         assert!(matches!(
             hand,
-            Hand::Lone(Card {
+            Action::Play(Hand::Lone(Card {
                 rank: Rank::Four,
                 suit: Suit::Spades
-            })
+            }))
         ));
 
         let hand_to_beat: Hand = "4H 4C".parse().unwrap();
         let player_cards = vec_card_from_str("4D 4S 5C");
         let hand = play_smallest_single_or_pass(&hand_to_beat, &player_cards);
-        assert!(matches!(hand, Hand::Pass));
+        assert!(matches!(hand, Action::Pass));
 
         let hand_to_beat: Hand = "6C".parse().unwrap();
         let player_cards = vec_card_from_str("4D 4S 5C");
         let hand = play_smallest_single_or_pass(&hand_to_beat, &player_cards);
-        assert!(matches!(hand, Hand::Pass));
+        assert!(matches!(hand, Action::Pass));
     }
 
     #[test]
     fn test_play_three_of_clubs() {
         let cards = vec_card_from_str("3C 4C 5D 2S");
-        let hand = play_three_of_clubs(&cards);
-        assert!(matches!(hand, Hand::Lone(a) if a == THREE_OF_CLUBS));
+        let hand = play_three_of_clubs(&cards).unwrap();
+        assert!(matches!(hand, Action::Play(Hand::Lone(a)) if a == THREE_OF_CLUBS));
 
         let cards = vec_card_from_str("3C 3D 5D 2S");
-        let hand = play_three_of_clubs(&cards);
-        assert!(matches!(hand, Hand::Pair(_, a) if a == THREE_OF_CLUBS));
+        let hand = play_three_of_clubs(&cards).unwrap();
+        assert!(matches!(hand, Action::Play(Hand::Pair(_, a)) if a == THREE_OF_CLUBS));
 
         let cards = vec_card_from_str("3C 3D 3S 2S");
+        let hand = play_three_of_clubs(&cards).unwrap();
+        assert!(matches!(hand, Action::Play(Hand::Trips(_, _, a)) if a == THREE_OF_CLUBS));
+
+        let cards = vec_card_from_str("3D 3S 2S");
         let hand = play_three_of_clubs(&cards);
-        assert!(matches!(hand, Hand::Trips(_, _, a) if a == THREE_OF_CLUBS));
+        assert!(matches!(hand, Err(AiInputError::NoThreeOfClubs)));
+    }
+
+    #[test]
+    fn test_start_trick_with_lowest_single() {
+        let cards = vec_card_from_str("5D 4S 2S");
+        let hand = start_trick_with_lowest_single(&cards).unwrap();
+        assert_eq!(hand.to_string(), "4S");
+
+        let hand = start_trick_with_lowest_single(&[]);
+        assert!(matches!(hand, Err(AiInputError::NoCards)));
     }
 }
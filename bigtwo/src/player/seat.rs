@@ -0,0 +1,205 @@
+//! Identifies the Players: a Seat is their position at the table, a PlayerId is who they are.
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::NUM_PLAYERS;
+
+/// A position at the table, from 0 to NUM_PLAYERS - 1, play proceeds to the next Seat.
+/// Can only be constructed for Seats that exist, so it is always safe to index players with.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(try_from = "usize", into = "usize")]
+pub struct Seat(usize);
+
+/// Returned when trying to make a Seat that is not at the table.
+#[derive(Debug)]
+pub struct NoSuchSeatError(pub usize);
+
+impl Display for NoSuchSeatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "there is no seat {}, only {} seats", self.0, NUM_PLAYERS)
+    }
+}
+
+impl Seat {
+    /// Returns the Seat at this index, or an Error if the table does not have that many Seats.
+    pub fn new(index: usize) -> Result<Seat, NoSuchSeatError> {
+        if index < NUM_PLAYERS {
+            Ok(Seat(index))
+        } else {
+            Err(NoSuchSeatError(index))
+        }
+    }
+
+    /// Every Seat at the table, in turn order.
+    pub fn all() -> [Seat; NUM_PLAYERS] {
+        core::array::from_fn(Seat)
+    }
+
+    /// The index of this Seat, e.g. into a [Player; NUM_PLAYERS].
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    /// The Seat that plays after this one, wrapping around the table.
+    pub fn next(&self) -> Seat {
+        Seat((self.0 + 1) % NUM_PLAYERS)
+    }
+}
+
+impl TryFrom<usize> for Seat {
+    type Error = NoSuchSeatError;
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        Seat::new(index)
+    }
+}
+
+impl From<Seat> for usize {
+    fn from(seat: Seat) -> Self {
+        seat.0
+    }
+}
+
+impl Display for Seat {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A set of Seats, stored as one bit per Seat so it is Copy and as cheap to compare as a u8.
+/// Serializes as a list of Seats.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "Vec<Seat>", into = "Vec<Seat>")]
+pub struct SeatSet(u8);
+
+impl SeatSet {
+    /// No Seats.
+    pub const fn new() -> Self {
+        SeatSet(0)
+    }
+
+    /// Returns true if the Seat was not already in the set.
+    pub fn insert(&mut self, seat: Seat) -> bool {
+        let had = self.contains(&seat);
+        self.0 |= 1 << seat.0;
+        !had
+    }
+
+    /// Returns true if the Seat was in the set.
+    pub fn remove(&mut self, seat: &Seat) -> bool {
+        let had = self.contains(seat);
+        self.0 &= !(1 << seat.0);
+        had
+    }
+
+    pub fn contains(&self, seat: &Seat) -> bool {
+        self.0 & (1 << seat.0) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = 0;
+    }
+
+    /// The Seats in either set.
+    pub fn union(&self, other: &SeatSet) -> SeatSet {
+        SeatSet(self.0 | other.0)
+    }
+
+    /// The Seats in the set, in turn order.
+    pub fn iter(&self) -> impl Iterator<Item = Seat> + '_ {
+        Seat::all().into_iter().filter(|seat| self.contains(seat))
+    }
+}
+
+impl FromIterator<Seat> for SeatSet {
+    fn from_iter<I: IntoIterator<Item = Seat>>(iter: I) -> Self {
+        let mut set = SeatSet::new();
+        for seat in iter {
+            set.insert(seat);
+        }
+        set
+    }
+}
+
+impl From<Vec<Seat>> for SeatSet {
+    fn from(seats: Vec<Seat>) -> Self {
+        seats.into_iter().collect()
+    }
+}
+
+impl From<SeatSet> for Vec<Seat> {
+    fn from(set: SeatSet) -> Self {
+        set.iter().collect()
+    }
+}
+
+/// A stable identity for a Player, which unlike their Seat or name does not change.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub struct PlayerId(pub u64);
+
+impl Display for PlayerId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_seat() {
+        assert!(Seat::new(NUM_PLAYERS - 1).is_ok());
+        assert!(matches!(Seat::new(NUM_PLAYERS), Err(NoSuchSeatError(_))));
+
+        let seats = Seat::all();
+        assert_eq!(seats.len(), NUM_PLAYERS);
+        for (index, seat) in seats.iter().enumerate() {
+            assert_eq!(seat.index(), index);
+            assert_eq!(seat.next().index(), (index + 1) % NUM_PLAYERS);
+        }
+    }
+
+    #[test]
+    fn test_seat_set() {
+        let mut set = SeatSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Seat::new(3).unwrap()));
+        assert!(set.insert(Seat::new(1).unwrap()));
+        assert!(!set.insert(Seat::new(1).unwrap()));
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            set.iter().map(|seat| seat.index()).collect::<Vec<_>>(),
+            [1, 3]
+        );
+        assert!(set.remove(&Seat::new(3).unwrap()));
+        assert!(!set.contains(&Seat::new(3).unwrap()));
+        assert_eq!(serde_json::to_string(&set).unwrap(), "[1]");
+        assert_eq!(serde_json::from_str::<SeatSet>("[1]").unwrap(), set);
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_seat_serde() {
+        let seat = Seat::new(2).unwrap();
+        assert_eq!(serde_json::to_string(&seat).unwrap(), "2");
+        assert_eq!(serde_json::from_str::<Seat>("2").unwrap(), seat);
+        assert!(serde_json::from_str::<Seat>("4").is_err());
+    }
+}
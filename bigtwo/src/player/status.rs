@@ -0,0 +1,32 @@
+//! Where a Player is in the life of their Seat: present, away, replaced by a bot, or gone.
+
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::player::controller::Controller;
+
+/// Whether the Player at a Seat is still taking part, and who is deciding their plays.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SeatStatus {
+    /// Playing as normal, by their own Controller.
+    #[default]
+    Present,
+    /// Lost their connection, their turns wait for them (or for their time to run out).
+    Disconnected,
+    /// A bot is playing for them, until they reclaim the Seat and get this Controller back.
+    TakenOver(Controller),
+    /// Forfeited, e.g. by resigning, they take no further part in the Game.
+    Forfeited,
+}
+
+impl Display for SeatStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            SeatStatus::Present => write!(f, "Present"),
+            SeatStatus::Disconnected => write!(f, "Disconnected"),
+            SeatStatus::TakenOver(_) => write!(f, "Taken over by a bot"),
+            SeatStatus::Forfeited => write!(f, "Forfeited"),
+        }
+    }
+}
@@ -1,6 +1,11 @@
 //! Utilities that are only useful for making unittest fixtures or assertions.
 
+use alloc::vec::Vec;
+
 use crate::card::Card;
+use crate::dealer::prearranged::Prearranged;
+use crate::game::Game;
+use crate::player::seat::Seat;
 
 /// Useful for making unittest fixtures, expects them to be correct (calls unwrap()).
 pub fn vec_card_from_str(input: &str) -> Vec<Card> {
@@ -9,3 +14,29 @@ pub fn vec_card_from_str(input: &str) -> Vec<Card> {
         .map(|x| x.parse().unwrap())
         .collect::<Vec<Card>>()
 }
+
+/// The Seat at this index, expects it to be one (calls unwrap()).
+pub fn seat(index: usize) -> Seat {
+    Seat::new(index).unwrap()
+}
+
+/// A Game dealt in order, so each Seat holds one Suit and Seat 0 the Clubs, after these inputs
+/// to Game::step. Expects them all to be allowed (calls unwrap()).
+pub fn in_order_game(inputs: &[&str]) -> Game {
+    let mut game = Game::with_dealer(&mut Prearranged::in_order()).unwrap();
+    for input in inputs {
+        game.step(input).unwrap();
+    }
+    game
+}
+
+/// A Game shuffled with this seed, see Game::with_seed, after this many turns, each taken by a
+/// Basic bot. Expects the Game to last that long (calls unwrap()).
+pub fn played_game(seed: u64, turns: usize) -> Game {
+    let mut game = Game::with_seed(seed);
+    for _ in 0..turns {
+        let action = game.get_npc_turn().unwrap();
+        game.apply(game.current_seat(), action).unwrap();
+    }
+    game
+}